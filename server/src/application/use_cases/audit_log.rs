@@ -0,0 +1,81 @@
+use anyhow::Result;
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::error;
+
+use crate::domain::{
+    entities::audit_log::AddAuditLogEntity,
+    repositories::audit_log::AuditLogRepository,
+    value_objects::audit_log::{
+        AuditAction, AuditContext, AuditLogModel, AuditLogPage, AuditLogQuery, AuditTarget,
+    },
+};
+
+/// Writes one audit entry. The action it describes has already happened, so a failure is
+/// logged rather than returned.
+pub async fn record<T>(
+    audit_log_repository: &T,
+    context: &AuditContext,
+    actor_id: i32,
+    action: AuditAction,
+    target: AuditTarget,
+    before: Option<Value>,
+    after: Option<Value>,
+) where
+    T: AuditLogRepository + Send + Sync,
+{
+    let add_audit_log_entity = AddAuditLogEntity {
+        actor_id,
+        action: action.to_string(),
+        target_type: target.target_type().to_string(),
+        target_id: target.target_id(),
+        mission_id: Some(target.mission_id()),
+        before,
+        after,
+        request_id: context.request_id.clone(),
+        ip_address: context.ip_address.clone(),
+    };
+
+    if let Err(e) = audit_log_repository.record(add_audit_log_entity).await {
+        error!(
+            "Failed to record audit entry {} by brawler {} on {:?}: {}",
+            action, actor_id, target, e
+        );
+    }
+}
+
+pub struct AuditLogUseCase<T>
+where
+    T: AuditLogRepository + Send + Sync,
+{
+    audit_log_repository: Arc<T>,
+}
+
+impl<T> AuditLogUseCase<T>
+where
+    T: AuditLogRepository + Send + Sync,
+{
+    pub fn new(audit_log_repository: Arc<T>) -> Self {
+        Self {
+            audit_log_repository,
+        }
+    }
+
+    pub async fn search(&self, query: AuditLogQuery) -> Result<AuditLogPage> {
+        let items = self
+            .audit_log_repository
+            .search(&query)
+            .await?
+            .into_iter()
+            .map(AuditLogModel::from)
+            .collect();
+        let total = self.audit_log_repository.counting(&query).await?;
+
+        Ok(AuditLogPage {
+            items,
+            page: query.page(),
+            per_page: query.per_page(),
+            total,
+        })
+    }
+}
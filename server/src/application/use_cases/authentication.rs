@@ -1,45 +1,202 @@
 use crate::{
-    domain::repositories::brawlers::BrawlerRepository,
+    config::config_model::LoginThrottleEnv,
+    domain::{
+        entities::login_failures::AddLoginFailureEntity,
+        repositories::{brawlers::BrawlerRepository, login_attempts::LoginAttemptRepository},
+        value_objects::login_throttle::{
+            self, INVALID_CREDENTIALS, LoginFailureReasons, LoginLocked,
+        },
+    },
     infrastructure::{
         argon2,
-        jwt::{authentication_model::LoginModel, jwt_model::Passport},
+        jwt::{authentication_model::LoginModel, jwt_model::Passport, keys::JwtKeys},
     },
 };
 use anyhow::Result;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
+use tracing::warn;
+
+/// Verified against when the username is unknown, so both failure paths cost one argon2 run.
+fn dummy_password_hash() -> &'static str {
+    static DUMMY_HASH: OnceLock<String> = OnceLock::new();
+    DUMMY_HASH.get_or_init(|| argon2::hash("dummy-password".to_string()).unwrap_or_default())
+}
 
-pub struct AuthenticationUseCase<T>
+pub struct AuthenticationUseCase<T1, T2>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: LoginAttemptRepository + Send + Sync,
 {
-    brawler_repository: Arc<T>,
+    brawler_repository: Arc<T1>,
+    login_attempt_repository: Arc<T2>,
+    login_throttle_env: LoginThrottleEnv,
+    jwt_keys: Arc<JwtKeys>,
 }
 
-impl<T> AuthenticationUseCase<T>
+impl<T1, T2> AuthenticationUseCase<T1, T2>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: LoginAttemptRepository + Send + Sync,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        login_attempt_repository: Arc<T2>,
+        login_throttle_env: LoginThrottleEnv,
+        jwt_keys: Arc<JwtKeys>,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            login_attempt_repository,
+            login_throttle_env,
+            jwt_keys,
+        }
     }
 
-    pub async fn login(&self, login_model: LoginModel) -> Result<Passport> {
+    pub async fn login(
+        &self,
+        login_model: LoginModel,
+        ip_address: Option<String>,
+    ) -> Result<Passport> {
         let username = login_model.username.clone();
+        let user_key = login_throttle::user_key(&username);
+        let ip_key = ip_address.as_deref().map(login_throttle::ip_key);
+
+        let throttle_keys = std::iter::once(user_key.clone())
+            .chain(ip_key.clone())
+            .collect();
+        if let Some(locked_until) = self
+            .login_attempt_repository
+            .locked_until(throttle_keys)
+            .await?
+        {
+            self.audit_failure(&username, &ip_address, LoginFailureReasons::Locked)
+                .await;
+            let remaining = locked_until - chrono::Utc::now().naive_utc();
+            let retry_after = ((remaining.num_milliseconds() + 999) / 1000).max(1);
+            return Err(LoginLocked { retry_after }.into());
+        }
 
         let brawler_entity = self.brawler_repository.find_by_username(username).await?;
-        let hash_password = brawler_entity.password;
-        let login_password = login_model.password;
 
-        if !argon2::verify(login_password, hash_password)? {
-            return Err(anyhow::anyhow!("Invalid password!"));
+        let Some(brawler_entity) = brawler_entity else {
+            let _ = argon2::verify(login_model.password, dummy_password_hash().to_string());
+            self.record_failure(
+                &login_model.username,
+                &ip_address,
+                LoginFailureReasons::UnknownUser,
+            )
+            .await;
+            return Err(anyhow::anyhow!(INVALID_CREDENTIALS));
+        };
+
+        if !argon2::verify(login_model.password, brawler_entity.password)? {
+            self.record_failure(
+                &login_model.username,
+                &ip_address,
+                LoginFailureReasons::WrongPassword,
+            )
+            .await;
+            return Err(anyhow::anyhow!(INVALID_CREDENTIALS));
         }
 
+        // Only the username's counter is cleared; a shared IP keeps its history.
+        self.login_attempt_repository.reset(user_key).await?;
+
+        let achievements = self
+            .brawler_repository
+            .get_achievements(brawler_entity.id)
+            .await?
+            .into_iter()
+            .map(|unlocked| unlocked.achievement)
+            .collect();
+
         let passport = Passport::new(
+            &self.jwt_keys,
             brawler_entity.id,
             brawler_entity.display_name,
             brawler_entity.avatar_url,
+            brawler_entity.xp,
+            achievements,
         )?;
 
         Ok(passport)
     }
+
+    /// Audits the failure and bumps the username and IP counters, locking whichever of them
+    /// crossed its limit. Errors are only logged so the caller still gets the uniform error.
+    async fn record_failure(
+        &self,
+        username: &str,
+        ip_address: &Option<String>,
+        reason: LoginFailureReasons,
+    ) {
+        self.audit_failure(username, ip_address, reason).await;
+
+        let env = &self.login_throttle_env;
+        let mut counters = vec![(
+            login_throttle::user_key(username),
+            env.max_failures_per_user,
+        )];
+        if let Some(ip_address) = ip_address {
+            counters.push((login_throttle::ip_key(ip_address), env.max_failures_per_ip));
+        }
+
+        let now = chrono::Utc::now().naive_utc();
+        let window_start = now - chrono::Duration::seconds(env.failure_window);
+
+        for (throttle_key, max_failures) in counters {
+            let failures = match self
+                .login_attempt_repository
+                .register_failure(throttle_key.clone(), window_start)
+                .await
+            {
+                Ok(failures) => failures,
+                Err(e) => {
+                    warn!("Failed to count login failure for {}: {}", throttle_key, e);
+                    continue;
+                }
+            };
+
+            let Some(seconds) = login_throttle::lockout_seconds(
+                failures,
+                max_failures,
+                env.lockout_base,
+                env.lockout_max,
+            ) else {
+                continue;
+            };
+
+            warn!(
+                "Locking {} for {}s after {} failed logins",
+                throttle_key, seconds, failures
+            );
+            if let Err(e) = self
+                .login_attempt_repository
+                .lock(
+                    throttle_key.clone(),
+                    now + chrono::Duration::seconds(seconds),
+                )
+                .await
+            {
+                warn!("Failed to lock {}: {}", throttle_key, e);
+            }
+        }
+    }
+
+    async fn audit_failure(
+        &self,
+        username: &str,
+        ip_address: &Option<String>,
+        reason: LoginFailureReasons,
+    ) {
+        let entity = AddLoginFailureEntity {
+            username: username.chars().take(255).collect(),
+            ip_address: ip_address.clone(),
+            reason: reason.to_string(),
+        };
+
+        if let Err(e) = self.login_attempt_repository.add_failure(entity).await {
+            warn!("Failed to audit login failure for {}: {}", username, e);
+        }
+    }
 }
@@ -0,0 +1,127 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::domain::{
+    entities::brawler_ratings::BrawlerRatingEntity,
+    repositories::{
+        brawler_ratings::BrawlerRatingRepository, mission_viewing::MissionViewingRepository,
+    },
+    value_objects::{
+        brawler_rating_model::{
+            AddBrawlerRatingModel, MAX_RATING_COMMENT_LENGTH, MAX_RATING_SCORE, MIN_RATING_SCORE,
+        },
+        mission_statuses::MissionStatuses,
+    },
+};
+
+pub struct BrawlerRatingsUseCase<T1, T2>
+where
+    T1: BrawlerRatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    brawler_rating_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+}
+
+impl<T1, T2> BrawlerRatingsUseCase<T1, T2>
+where
+    T1: BrawlerRatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    pub fn new(brawler_rating_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+        Self {
+            brawler_rating_repository,
+            mission_viewing_repository,
+        }
+    }
+
+    fn validate(add_rating_model: &AddBrawlerRatingModel) -> Result<()> {
+        if !(MIN_RATING_SCORE..=MAX_RATING_SCORE).contains(&add_rating_model.score) {
+            return Err(anyhow::anyhow!(
+                "Score must be between {} and {}!",
+                MIN_RATING_SCORE,
+                MAX_RATING_SCORE
+            ));
+        }
+        if let Some(comment) = &add_rating_model.comment
+            && comment.trim().chars().count() > MAX_RATING_COMMENT_LENGTH
+        {
+            return Err(anyhow::anyhow!(
+                "Comment must be at most {} characters long!",
+                MAX_RATING_COMMENT_LENGTH
+            ));
+        }
+        Ok(())
+    }
+
+    /// Ratings carry rater and ratee ids and comments, so only the chief and crew see them.
+    pub async fn gets(&self, mission_id: i32, viewer_id: i32) -> Result<Vec<BrawlerRatingEntity>> {
+        let mission = self
+            .mission_viewing_repository
+            .view_detail(mission_id)
+            .await?;
+
+        let can_view = mission.chief_id == viewer_id
+            || self
+                .mission_viewing_repository
+                .is_crew_member(mission_id, viewer_id)
+                .await?;
+        if !can_view {
+            return Err(anyhow::anyhow!(
+                "Only the chief and crew can view ratings of this mission"
+            ));
+        }
+
+        self.brawler_rating_repository.gets(mission_id).await
+    }
+
+    /// The chief rates crew members and crew members rate the chief, once per mission.
+    pub async fn rate(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+        ratee_id: i32,
+        add_rating_model: AddBrawlerRatingModel,
+    ) -> Result<i32> {
+        Self::validate(&add_rating_model)?;
+
+        if rater_id == ratee_id {
+            return Err(anyhow::anyhow!("You cannot rate yourself"));
+        }
+
+        let mission = self
+            .mission_viewing_repository
+            .view_detail(mission_id)
+            .await?;
+
+        let is_finished = mission.status == MissionStatuses::Completed.to_string()
+            || mission.status == MissionStatuses::Failed.to_string();
+        if !is_finished {
+            return Err(anyhow::anyhow!(
+                "Ratings open once the mission is Completed or Failed"
+            ));
+        }
+
+        let can_rate = if mission.chief_id == rater_id {
+            self.mission_viewing_repository
+                .is_crew_member(mission_id, ratee_id)
+                .await?
+        } else {
+            mission.chief_id == ratee_id
+                && self
+                    .mission_viewing_repository
+                    .is_crew_member(mission_id, rater_id)
+                    .await?
+        };
+
+        if !can_rate {
+            return Err(anyhow::anyhow!(
+                "Only the chief and crew of this mission can rate each other"
+            ));
+        }
+
+        self.brawler_rating_repository
+            .add(add_rating_model.to_entity(mission_id, rater_id, ratee_id))
+            .await
+    }
+}
@@ -1,42 +1,68 @@
 use crate::{
     domain::{
         entities::brawlers::BrawlerEntity,
-        repositories::brawlers::BrawlerRepository,
+        repositories::{brawlers::BrawlerRepository, image_processor::ImageProcessor},
         value_objects::{
             base64_image::Base64Image,
-            brawler_model::RegisterBrawlerModel,
+            brawler_model::{BrawlerProfile, RegisterBrawlerModel},
+            credentials::{self, ChangePasswordModel, CredentialsError},
             mission_model::MissionModel,
-            uploaded_image::{UploadImageOptions, UploadedImage},
+            uploaded_image::{AVATAR_SIZES, UploadImageOptions, UploadedAvatar},
+            validation::{Validate, ValidationErrors},
         },
     },
-    infrastructure::{argon2::hash, jwt::jwt_model::Passport},
+    infrastructure::{
+        argon2::{hash, verify},
+        jwt::{jwt_model::Passport, keys::JwtKeys},
+    },
 };
 use anyhow::Result;
 use std::sync::Arc;
 
+const PROFILE_RECENT_MISSIONS: i64 = 5;
+
 pub struct BrawlersUseCase<T>
 where
     T: BrawlerRepository + Send + Sync,
 {
     brawler_repository: Arc<T>,
+    image_processor: Arc<dyn ImageProcessor>,
+    jwt_keys: Arc<JwtKeys>,
 }
 
 impl<T> BrawlersUseCase<T>
 where
     T: BrawlerRepository + Send + Sync,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
+    pub fn new(
+        brawler_repository: Arc<T>,
+        image_processor: Arc<dyn ImageProcessor>,
+        jwt_keys: Arc<JwtKeys>,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            image_processor,
+            jwt_keys,
+        }
     }
 
     pub async fn register(&self, mut register_model: RegisterBrawlerModel) -> Result<Passport> {
+        register_model.validate()?;
+
         register_model.password = hash(register_model.password.clone())?;
 
         let register_entity = register_model.to_entity();
 
         let brawler_id = self.brawler_repository.register(register_entity).await?;
 
-        let passport = Passport::new(brawler_id, register_model.display_name.clone(), None)?;
+        let passport = Passport::new(
+            &self.jwt_keys,
+            brawler_id,
+            register_model.display_name.trim().to_string(),
+            None,
+            0,
+            Vec::new(),
+        )?;
 
         Ok(passport)
     }
@@ -44,23 +70,32 @@ where
     pub async fn upload_avatar(
         &self,
         brawler_id: i32,
-        base64_image: String,
-        _option: UploadImageOptions,
-    ) -> Result<UploadedImage> {
+        base64_image: Base64Image,
+    ) -> Result<UploadedAvatar> {
         let option = UploadImageOptions {
             folder: Some("brawlers_avatar".to_string()),
-            public_id: Some(brawler_id.to_string()),
-            transformation: Some("c_scale,w_256".to_string()),
+            // A fresh public id per upload, so the previous avatar can be destroyed afterwards.
+            public_id: Some(format!("{}_{}", brawler_id, chrono::Utc::now().timestamp())),
+            transformation: None,
         };
-        let base64_image = Base64Image::new(base64_image)?;
+        // Decoding and resizing a large upload takes a while; keep it off the async workers.
+        let image_processor = Arc::clone(&self.image_processor);
+        let variants = tokio::task::spawn_blocking(move || {
+            image_processor.square_variants(&base64_image, &AVATAR_SIZES)
+        })
+        .await??;
 
         let uploaded = self
             .brawler_repository
-            .upload_avatar(brawler_id, base64_image, option)
+            .upload_avatar(brawler_id, variants, option)
             .await?;
         Ok(uploaded)
     }
 
+    pub async fn remove_avatar(&self, brawler_id: i32) -> Result<()> {
+        self.brawler_repository.remove_avatar(brawler_id).await
+    }
+
     pub async fn get_brawlers_by_mission_id(&self, mission_id: i32) -> Result<Vec<BrawlerEntity>> {
         self.brawler_repository
             .get_brawlers_by_mission_id(mission_id)
@@ -69,11 +104,67 @@ where
 
     pub async fn update_profile(&self, brawler_id: i32, display_name: String) -> Result<()> {
         self.brawler_repository
-            .update_profile(brawler_id, display_name)
+            .update_profile(brawler_id, display_name.trim().to_string())
+            .await
+    }
+
+    pub async fn change_password(
+        &self,
+        brawler_id: i32,
+        change_password_model: ChangePasswordModel,
+    ) -> Result<()> {
+        let brawler = self.brawler_repository.find_by_id(brawler_id).await?;
+
+        if !verify(
+            change_password_model.current_password.clone(),
+            brawler.password,
+        )? {
+            return Err(CredentialsError::WrongCurrentPassword.into());
+        }
+
+        let mut errors = ValidationErrors::new();
+        if change_password_model.new_password == change_password_model.current_password {
+            errors.add(
+                "new_password",
+                "unchanged",
+                "New password must differ from the current one!",
+            );
+        }
+        errors.check(
+            "new_password",
+            credentials::validate_password(&change_password_model.new_password, &brawler.username),
+        );
+        errors.into_result()?;
+
+        let hashed_password = hash(change_password_model.new_password)?;
+        self.brawler_repository
+            .update_password(brawler_id, hashed_password)
             .await
     }
 
     pub async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
         self.brawler_repository.get_missions(brawler_id).await
     }
+
+    pub async fn get_profile(&self, brawler_id: i32) -> Result<Option<BrawlerProfile>> {
+        let Some(stats) = self
+            .brawler_repository
+            .get_profile_stats(brawler_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let recent_missions = self
+            .brawler_repository
+            .get_recent_missions(brawler_id, PROFILE_RECENT_MISSIONS)
+            .await?;
+        let achievements = self.brawler_repository.get_achievements(brawler_id).await?;
+
+        Ok(Some(BrawlerProfile::new(
+            stats,
+            recent_missions,
+            achievements,
+        )))
+    }
 }
@@ -1,47 +1,59 @@
-use crate::domain::{
-    entities::crew_memberships::CrewMemberShips,
-    entities::missions::EditMissionEntity,
-    repositories::{
-        crew_operation::CrewOperationRepository, mission_management::MissionManagementRepository,
-        mission_viewing::MissionViewingRepository,
+use crate::{
+    application::use_cases::audit_log,
+    domain::{
+        entities::crew_memberships::CrewMemberShips,
+        entities::missions::EditMissionEntity,
+        repositories::{
+            audit_log::AuditLogRepository, crew_operation::CrewOperationRepository,
+            mission_management::MissionManagementRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            audit_log::{AuditAction, AuditContext, AuditTarget},
+            departure_reasons::DepartureReasons,
+            mission_statuses::MissionStatuses,
+        },
     },
-    value_objects::mission_statuses::MissionStatuses,
 };
 use anyhow::Result;
+use serde_json::json;
 use std::sync::Arc;
 
-// Note: MAX_CREW_PER_MISSION is now read from mission.max_crew, this constant is deprecated
-
-pub struct CrewOperationUseCase<T1, T2, T3>
+pub struct CrewOperationUseCase<T1, T2, T3, T4>
 where
     T1: CrewOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
     T3: MissionManagementRepository + Send + Sync,
+    T4: AuditLogRepository + Send + Sync,
 {
     crew_operation_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
     mission_management_repository: Arc<T3>,
+    audit_log_repository: Arc<T4>,
 }
 
-impl<T1, T2, T3> CrewOperationUseCase<T1, T2, T3>
+impl<T1, T2, T3, T4> CrewOperationUseCase<T1, T2, T3, T4>
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
     T3: MissionManagementRepository + Send + Sync,
+    T4: AuditLogRepository + Send + Sync,
 {
     pub fn new(
         crew_operation_repository: Arc<T1>,
         mission_viewing_repository: Arc<T2>,
         mission_management_repository: Arc<T3>,
+        audit_log_repository: Arc<T4>,
     ) -> Self {
         Self {
             crew_operation_repository,
             mission_viewing_repository,
             mission_management_repository,
+            audit_log_repository,
         }
     }
 
-    pub async fn join(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
+    pub async fn join(&self, mission_id: i32, brawler_id: i32, audit: AuditContext) -> Result<()> {
         let mission = self
             .mission_viewing_repository
             .view_detail(mission_id)
@@ -76,6 +88,7 @@ where
                         max_crew: None,
                         deadline: None,
                         duration: None,
+                        require_objectives: None,
                     },
                 )
                 .await?;
@@ -89,10 +102,24 @@ where
             })
             .await?;
 
+        audit_log::record(
+            self.audit_log_repository.as_ref(),
+            &audit,
+            brawler_id,
+            AuditAction::CrewJoined,
+            AuditTarget::CrewMember {
+                mission_id,
+                brawler_id,
+            },
+            None,
+            Some(json!({ "crew_member": brawler_id })),
+        )
+        .await;
+
         Ok(())
     }
 
-    pub async fn leave(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
+    pub async fn leave(&self, mission_id: i32, brawler_id: i32, audit: AuditContext) -> Result<()> {
         let mission = self
             .mission_viewing_repository
             .view_detail(mission_id)
@@ -111,11 +138,28 @@ where
         }
 
         self.crew_operation_repository
-            .leave(CrewMemberShips {
+            .leave(
+                CrewMemberShips {
+                    mission_id,
+                    brawler_id,
+                },
+                DepartureReasons::Left,
+            )
+            .await?;
+
+        audit_log::record(
+            self.audit_log_repository.as_ref(),
+            &audit,
+            brawler_id,
+            AuditAction::CrewLeft,
+            AuditTarget::CrewMember {
                 mission_id,
                 brawler_id,
-            })
-            .await?;
+            },
+            Some(json!({ "crew_member": brawler_id })),
+            None,
+        )
+        .await;
 
         // Change mission status back to Open so it appears in Browse Missions,
         // BUT only if it is not Completed/InProgress, and if it was "Failed" due to being Full.
@@ -143,6 +187,7 @@ where
                         max_crew: None,
                         deadline: None,
                         duration: None,
+                        require_objectives: None,
                     },
                 )
                 .await?;
@@ -151,7 +196,13 @@ where
         Ok(())
     }
 
-    pub async fn kick_member(&self, mission_id: i32, brawler_id: i32, chief_id: i32) -> Result<()> {
+    pub async fn kick_member(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        chief_id: i32,
+        audit: AuditContext,
+    ) -> Result<()> {
         let mission = self
             .mission_viewing_repository
             .view_detail(mission_id)
@@ -188,11 +239,28 @@ where
         }
 
         self.crew_operation_repository
-            .leave(CrewMemberShips {
+            .leave(
+                CrewMemberShips {
+                    mission_id,
+                    brawler_id,
+                },
+                DepartureReasons::Kicked,
+            )
+            .await?;
+
+        audit_log::record(
+            self.audit_log_repository.as_ref(),
+            &audit,
+            chief_id,
+            AuditAction::CrewKicked,
+            AuditTarget::CrewMember {
                 mission_id,
                 brawler_id,
-            })
-            .await?;
+            },
+            Some(json!({ "crew_member": brawler_id })),
+            None,
+        )
+        .await;
 
         // If mission was Full (Failed), set it back to Open
         if mission.status == MissionStatuses::Failed.to_string() {
@@ -214,6 +282,7 @@ where
                             max_crew: None,
                             deadline: None,
                             duration: None,
+                            require_objectives: None,
                         },
                     )
                     .await?;
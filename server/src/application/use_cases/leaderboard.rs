@@ -0,0 +1,51 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::domain::{
+    repositories::leaderboard::LeaderboardRepository,
+    value_objects::leaderboard::{Leaderboard, LeaderboardCategory, LeaderboardQuery},
+};
+
+pub struct LeaderboardUseCase<T>
+where
+    T: LeaderboardRepository + Send + Sync,
+{
+    leaderboard_repository: Arc<T>,
+    min_sample: i64,
+}
+
+impl<T> LeaderboardUseCase<T>
+where
+    T: LeaderboardRepository + Send + Sync,
+{
+    pub fn new(leaderboard_repository: Arc<T>, min_sample: i64) -> Self {
+        Self {
+            leaderboard_repository,
+            min_sample,
+        }
+    }
+
+    pub async fn get(
+        &self,
+        category: LeaderboardCategory,
+        query: LeaderboardQuery,
+    ) -> Result<Leaderboard> {
+        let period = query.period();
+
+        let entries = self
+            .leaderboard_repository
+            .ranking(category, period, self.min_sample, query.limit())
+            .await?;
+
+        Ok(Leaderboard {
+            category,
+            period,
+            min_sample: (category == LeaderboardCategory::SuccessRate).then_some(self.min_sample),
+            entries,
+        })
+    }
+
+    pub async fn refresh(&self) -> Result<()> {
+        self.leaderboard_repository.refresh().await
+    }
+}
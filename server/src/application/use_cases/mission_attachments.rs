@@ -0,0 +1,109 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::domain::{
+    entities::mission_attachments::MissionAttachmentEntity,
+    repositories::{
+        mission_attachments::MissionAttachmentRepository, mission_viewing::MissionViewingRepository,
+    },
+    value_objects::{base64_image::Base64Image, uploaded_image::UploadImageOptions},
+};
+
+const MAX_ATTACHMENTS_PER_MISSION: i64 = 10;
+
+pub struct MissionAttachmentsUseCase<T1, T2>
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    mission_attachment_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+}
+
+impl<T1, T2> MissionAttachmentsUseCase<T1, T2>
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    pub fn new(
+        mission_attachment_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+    ) -> Self {
+        Self {
+            mission_attachment_repository,
+            mission_viewing_repository,
+        }
+    }
+
+    pub async fn gets(&self, mission_id: i32) -> Result<Vec<MissionAttachmentEntity>> {
+        self.mission_viewing_repository
+            .view_detail(mission_id)
+            .await?;
+
+        self.mission_attachment_repository.gets(mission_id).await
+    }
+
+    pub async fn upload(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        base64_image: Base64Image,
+    ) -> Result<MissionAttachmentEntity> {
+        let mission = self
+            .mission_viewing_repository
+            .view_detail(mission_id)
+            .await?;
+
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!("Only the mission chief can attach images"));
+        }
+
+        let attachment_count = self
+            .mission_attachment_repository
+            .counting(mission_id)
+            .await?;
+
+        if attachment_count >= MAX_ATTACHMENTS_PER_MISSION {
+            return Err(anyhow::anyhow!(
+                "A mission can have at most {} attachments",
+                MAX_ATTACHMENTS_PER_MISSION
+            ));
+        }
+
+        let option = UploadImageOptions {
+            folder: Some(format!("mission_attachments/{}", mission_id)),
+            public_id: None,
+            transformation: None,
+        };
+
+        self.mission_attachment_repository
+            .upload(mission_id, chief_id, base64_image, option)
+            .await
+    }
+
+    pub async fn remove(&self, mission_id: i32, attachment_id: i32, chief_id: i32) -> Result<()> {
+        let mission = self
+            .mission_viewing_repository
+            .view_detail(mission_id)
+            .await?;
+
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!(
+                "Only the mission chief can remove attachments"
+            ));
+        }
+
+        let attachment = self
+            .mission_attachment_repository
+            .find_by_id(attachment_id)
+            .await?;
+
+        if attachment.mission_id != mission_id {
+            return Err(anyhow::anyhow!("Attachment not found"));
+        }
+
+        self.mission_attachment_repository
+            .remove(attachment_id)
+            .await
+    }
+}
@@ -0,0 +1,185 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::domain::{
+    repositories::{
+        mission_comments::MissionCommentRepository, mission_viewing::MissionViewingRepository,
+    },
+    value_objects::{
+        mission_comment_model::{
+            AddMissionCommentModel, CommentQuery, EditMissionCommentModel, MAX_COMMENT_LENGTH,
+            MissionCommentPage,
+        },
+        mission_model::MissionModel,
+        mission_statuses::MissionStatuses,
+    },
+};
+
+pub struct MissionCommentsUseCase<T1, T2>
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    mission_comment_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+    public_when_open: bool,
+}
+
+impl<T1, T2> MissionCommentsUseCase<T1, T2>
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    pub fn new(
+        mission_comment_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        public_when_open: bool,
+    ) -> Self {
+        Self {
+            mission_comment_repository,
+            mission_viewing_repository,
+            public_when_open,
+        }
+    }
+
+    async fn is_participant(&self, mission: &MissionModel, brawler_id: i32) -> Result<bool> {
+        if mission.chief_id == brawler_id {
+            return Ok(true);
+        }
+
+        self.mission_viewing_repository
+            .is_crew_member(mission.id, brawler_id)
+            .await
+    }
+
+    fn validate_content(content: &str) -> Result<()> {
+        let content = content.trim();
+        if content.is_empty() {
+            return Err(anyhow::anyhow!("Comment cannot be empty!"));
+        }
+        if content.chars().count() > MAX_COMMENT_LENGTH {
+            return Err(anyhow::anyhow!(
+                "Comment must be at most {} characters long!",
+                MAX_COMMENT_LENGTH
+            ));
+        }
+        Ok(())
+    }
+
+    pub async fn gets(
+        &self,
+        mission_id: i32,
+        viewer_id: Option<i32>,
+        query: CommentQuery,
+    ) -> Result<MissionCommentPage> {
+        let mission = self
+            .mission_viewing_repository
+            .view_detail(mission_id)
+            .await?;
+
+        let is_public =
+            self.public_when_open && mission.status == MissionStatuses::Open.to_string();
+        let can_view = match viewer_id {
+            Some(brawler_id) => is_public || self.is_participant(&mission, brawler_id).await?,
+            None => is_public,
+        };
+
+        if !can_view {
+            return Err(anyhow::anyhow!(
+                "Only the chief and crew can view comments of this mission"
+            ));
+        }
+
+        let items = self
+            .mission_comment_repository
+            .gets(mission_id, query.per_page(), query.offset())
+            .await?;
+
+        let total = self.mission_comment_repository.counting(mission_id).await?;
+
+        Ok(MissionCommentPage {
+            items,
+            page: query.page(),
+            per_page: query.per_page(),
+            total,
+        })
+    }
+
+    pub async fn add(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        add_comment_model: AddMissionCommentModel,
+    ) -> Result<i32> {
+        Self::validate_content(&add_comment_model.content)?;
+
+        let mission = self
+            .mission_viewing_repository
+            .view_detail(mission_id)
+            .await?;
+
+        if !self.is_participant(&mission, brawler_id).await? {
+            return Err(anyhow::anyhow!(
+                "Only the chief and crew can comment on this mission"
+            ));
+        }
+
+        self.mission_comment_repository
+            .add(add_comment_model.to_entity(mission_id, brawler_id))
+            .await
+    }
+
+    pub async fn edit(
+        &self,
+        mission_id: i32,
+        comment_id: i32,
+        brawler_id: i32,
+        edit_comment_model: EditMissionCommentModel,
+    ) -> Result<i32> {
+        Self::validate_content(&edit_comment_model.content)?;
+
+        let comment = self
+            .mission_comment_repository
+            .find_by_id(comment_id)
+            .await?;
+
+        if comment.mission_id != mission_id {
+            return Err(anyhow::anyhow!("Comment not found"));
+        }
+
+        if comment.brawler_id != brawler_id {
+            return Err(anyhow::anyhow!("Only the author can edit this comment"));
+        }
+
+        self.mission_comment_repository
+            .edit(comment_id, edit_comment_model.content.trim().to_string())
+            .await
+    }
+
+    pub async fn remove(&self, mission_id: i32, comment_id: i32, brawler_id: i32) -> Result<()> {
+        let comment = self
+            .mission_comment_repository
+            .find_by_id(comment_id)
+            .await?;
+
+        if comment.mission_id != mission_id {
+            return Err(anyhow::anyhow!("Comment not found"));
+        }
+
+        // The chief moderates the thread and may delete anyone's comment.
+        if comment.brawler_id != brawler_id {
+            let mission = self
+                .mission_viewing_repository
+                .view_detail(mission_id)
+                .await?;
+
+            if mission.chief_id != brawler_id {
+                return Err(anyhow::anyhow!(
+                    "Only the author or the mission chief can delete this comment"
+                ));
+            }
+        }
+
+        self.mission_comment_repository.remove(comment_id).await
+    }
+}
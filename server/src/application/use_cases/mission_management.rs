@@ -1,44 +1,55 @@
 use anyhow::Result;
 use std::sync::Arc;
 
-use crate::domain::{
-    repositories::{
-        mission_management::MissionManagementRepository, mission_viewing::MissionViewingRepository,
+use crate::{
+    application::use_cases::audit_log,
+    domain::{
+        repositories::{
+            audit_log::AuditLogRepository, mission_management::MissionManagementRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            audit_log::{AuditAction, AuditContext, AuditTarget, diff, mission_snapshot},
+            mission_model::{AddMissionModel, EditMissionModel, MissionModel},
+        },
     },
-    value_objects::mission_model::{AddMissionModel, EditMissionModel},
 };
 
-pub struct MissionManagementUseCase<T1, T2>
+pub struct MissionManagementUseCase<T1, T2, T3>
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: AuditLogRepository + Send + Sync,
 {
     mission_management_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
+    audit_log_repository: Arc<T3>,
 }
 
-impl<T1, T2> MissionManagementUseCase<T1, T2>
+impl<T1, T2, T3> MissionManagementUseCase<T1, T2, T3>
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: AuditLogRepository + Send + Sync,
 {
     pub fn new(
         mission_management_repository: Arc<T1>,
         mission_viewing_repository: Arc<T2>,
+        audit_log_repository: Arc<T3>,
     ) -> Self {
         Self {
             mission_management_repository,
             mission_viewing_repository,
+            audit_log_repository,
         }
     }
 
-    pub async fn add(&self, chief_id: i32, add_mission_model: AddMissionModel) -> Result<i32> {
-        if add_mission_model.name.is_empty() || add_mission_model.name.trim().len() < 3 {
-            return Err(anyhow::anyhow!(
-                "Mission name must be  least 4 characters long!"
-            ));
-        }
-
+    pub async fn add(
+        &self,
+        chief_id: i32,
+        add_mission_model: AddMissionModel,
+        audit: AuditContext,
+    ) -> Result<i32> {
         let insert_mission_entity = add_mission_model.to_entity(chief_id);
 
         let result = self
@@ -46,6 +57,18 @@ where
             .add(insert_mission_entity)
             .await?;
 
+        let after = self.snapshot(result).await;
+        audit_log::record(
+            self.audit_log_repository.as_ref(),
+            &audit,
+            chief_id,
+            AuditAction::MissionCreated,
+            AuditTarget::Mission(result),
+            None,
+            after,
+        )
+        .await;
+
         Ok(result)
     }
 
@@ -54,18 +77,13 @@ where
         mission_id: i32,
         chief_id: i32,
         mut edit_mission_model: EditMissionModel,
+        audit: AuditContext,
     ) -> Result<i32> {
-        if let Some(name) = edit_mission_model.name {
-            if name.trim().is_empty() {
-                edit_mission_model.name = None;
-            } else if name.trim().len() < 4 {
-                return Err(anyhow::anyhow!(
-                    "Mission name must be at least 4 characters long!"
-                ));
-            } else {
-                edit_mission_model.name = Some(name.trim().to_string());
-            }
-        }
+        // Length rules are enforced by `EditMissionModel::validate`; a blank name keeps the old one.
+        edit_mission_model.name = edit_mission_model
+            .name
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty());
 
         let crew_count = self
             .mission_viewing_repository
@@ -78,6 +96,7 @@ where
             ));
         }
 
+        let before = self.snapshot(mission_id).await;
         let edit_mission_entity = edit_mission_model.to_entity(chief_id);
 
         let result = self
@@ -85,10 +104,26 @@ where
             .edit(mission_id, edit_mission_entity)
             .await?;
 
+        let after = self.snapshot(mission_id).await;
+        let (before, after) = match (before, after) {
+            (Some(before), Some(after)) => diff(before, after),
+            (before, after) => (before, after),
+        };
+        audit_log::record(
+            self.audit_log_repository.as_ref(),
+            &audit,
+            chief_id,
+            AuditAction::MissionEdited,
+            AuditTarget::Mission(mission_id),
+            before,
+            after,
+        )
+        .await;
+
         Ok(result)
     }
 
-    pub async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
+    pub async fn remove(&self, mission_id: i32, chief_id: i32, audit: AuditContext) -> Result<()> {
         let mission = self
             .mission_viewing_repository
             .view_detail(mission_id)
@@ -113,6 +148,56 @@ where
             .remove(mission_id, chief_id)
             .await?;
 
+        audit_log::record(
+            self.audit_log_repository.as_ref(),
+            &audit,
+            chief_id,
+            AuditAction::MissionDeleted,
+            AuditTarget::Mission(mission_id),
+            Some(mission_snapshot(&mission)),
+            None,
+        )
+        .await;
+
         Ok(())
     }
+
+    pub async fn trash(&self, chief_id: i32) -> Result<Vec<MissionModel>> {
+        self.mission_viewing_repository.gets_deleted(chief_id).await
+    }
+
+    pub async fn restore(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        audit: AuditContext,
+    ) -> Result<i32> {
+        let result = self
+            .mission_management_repository
+            .restore(mission_id, chief_id)
+            .await?;
+
+        let after = self.snapshot(mission_id).await;
+        audit_log::record(
+            self.audit_log_repository.as_ref(),
+            &audit,
+            chief_id,
+            AuditAction::MissionRestored,
+            AuditTarget::Mission(mission_id),
+            None,
+            after,
+        )
+        .await;
+
+        Ok(result)
+    }
+
+    /// The mission as the audit log records it, `None` if it can't be read.
+    async fn snapshot(&self, mission_id: i32) -> Option<serde_json::Value> {
+        self.mission_viewing_repository
+            .view_detail(mission_id)
+            .await
+            .ok()
+            .map(|mission| mission_snapshot(&mission))
+    }
 }
@@ -0,0 +1,186 @@
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::domain::{
+    entities::mission_objectives::MissionObjectiveEntity,
+    repositories::{
+        mission_objectives::MissionObjectiveRepository, mission_viewing::MissionViewingRepository,
+    },
+    value_objects::{
+        mission_model::MissionModel,
+        mission_objective_model::{
+            AddMissionObjectiveModel, EditMissionObjectiveModel, MAX_OBJECTIVE_TITLE_LENGTH,
+        },
+        mission_statuses::MissionStatuses,
+    },
+};
+
+pub struct MissionObjectivesUseCase<T1, T2>
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    mission_objective_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+}
+
+impl<T1, T2> MissionObjectivesUseCase<T1, T2>
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    pub fn new(mission_objective_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+        Self {
+            mission_objective_repository,
+            mission_viewing_repository,
+        }
+    }
+
+    fn validate_title(title: &str) -> Result<()> {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(anyhow::anyhow!("Objective title cannot be empty!"));
+        }
+        if title.chars().count() > MAX_OBJECTIVE_TITLE_LENGTH {
+            return Err(anyhow::anyhow!(
+                "Objective title must be at most {} characters long!",
+                MAX_OBJECTIVE_TITLE_LENGTH
+            ));
+        }
+        Ok(())
+    }
+
+    async fn editable_mission(&self, mission_id: i32, chief_id: i32) -> Result<MissionModel> {
+        let mission = self
+            .mission_viewing_repository
+            .view_detail(mission_id)
+            .await?;
+
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!(
+                "Only the mission chief can manage objectives"
+            ));
+        }
+
+        let editable = mission.status == MissionStatuses::Open.to_string()
+            || mission.status == MissionStatuses::InProgress.to_string();
+        if !editable {
+            return Err(anyhow::anyhow!(
+                "Objectives can only be changed while the mission is Open or In Progress"
+            ));
+        }
+
+        Ok(mission)
+    }
+
+    async fn objective_of(
+        &self,
+        mission_id: i32,
+        objective_id: i32,
+    ) -> Result<MissionObjectiveEntity> {
+        let objective = self
+            .mission_objective_repository
+            .find_by_id(objective_id)
+            .await?;
+
+        if objective.mission_id != mission_id {
+            return Err(anyhow::anyhow!("Objective not found"));
+        }
+
+        Ok(objective)
+    }
+
+    pub async fn gets(&self, mission_id: i32) -> Result<Vec<MissionObjectiveEntity>> {
+        self.mission_viewing_repository
+            .view_detail(mission_id)
+            .await?;
+
+        self.mission_objective_repository.gets(mission_id).await
+    }
+
+    pub async fn add(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        add_objective_model: AddMissionObjectiveModel,
+    ) -> Result<i32> {
+        Self::validate_title(&add_objective_model.title)?;
+        self.editable_mission(mission_id, chief_id).await?;
+
+        let position = match add_objective_model.position {
+            Some(position) => position,
+            None => {
+                self.mission_objective_repository
+                    .next_position(mission_id)
+                    .await?
+            }
+        };
+
+        self.mission_objective_repository
+            .add(add_objective_model.to_entity(mission_id, position))
+            .await
+    }
+
+    pub async fn edit(
+        &self,
+        mission_id: i32,
+        objective_id: i32,
+        chief_id: i32,
+        edit_objective_model: EditMissionObjectiveModel,
+    ) -> Result<i32> {
+        if let Some(title) = &edit_objective_model.title {
+            Self::validate_title(title)?;
+        }
+        self.editable_mission(mission_id, chief_id).await?;
+        self.objective_of(mission_id, objective_id).await?;
+
+        self.mission_objective_repository
+            .edit(objective_id, edit_objective_model.to_entity())
+            .await
+    }
+
+    pub async fn remove(&self, mission_id: i32, objective_id: i32, chief_id: i32) -> Result<()> {
+        self.editable_mission(mission_id, chief_id).await?;
+        self.objective_of(mission_id, objective_id).await?;
+
+        self.mission_objective_repository.remove(objective_id).await
+    }
+
+    pub async fn set_completed(
+        &self,
+        mission_id: i32,
+        objective_id: i32,
+        brawler_id: i32,
+        completed: bool,
+    ) -> Result<i32> {
+        let mission = self
+            .mission_viewing_repository
+            .view_detail(mission_id)
+            .await?;
+
+        if mission.status != MissionStatuses::InProgress.to_string() {
+            return Err(anyhow::anyhow!(
+                "Objectives can only be ticked off while the mission is In Progress"
+            ));
+        }
+
+        let is_participant = mission.chief_id == brawler_id
+            || self
+                .mission_viewing_repository
+                .is_crew_member(mission_id, brawler_id)
+                .await?;
+        if !is_participant {
+            return Err(anyhow::anyhow!(
+                "Only the chief and crew can tick off objectives"
+            ));
+        }
+
+        self.objective_of(mission_id, objective_id).await?;
+
+        let completed_by = if completed { Some(brawler_id) } else { None };
+
+        self.mission_objective_repository
+            .set_completed(objective_id, completed_by)
+            .await
+    }
+}
@@ -1,35 +1,66 @@
 use anyhow::Result;
 use std::sync::Arc;
+use tracing::{info, warn};
 
-use crate::domain::{
-    repositories::{
-        mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository,
+use crate::{
+    application::use_cases::audit_log,
+    domain::{
+        repositories::{
+            audit_log::AuditLogRepository, mission_operation::MissionOperationRepository,
+            mission_viewing::MissionViewingRepository, progression::ProgressionRepository,
+        },
+        value_objects::{
+            audit_log::{AuditAction, AuditContext, AuditTarget, diff, mission_snapshot},
+            mission_model::MissionModel,
+            mission_statuses::MissionStatuses,
+            progression::{Achievement, mission_xp},
+        },
     },
-    value_objects::mission_statuses::MissionStatuses,
 };
 
-pub struct MissionOperationUseCase<T1, T2>
+pub struct MissionOperationUseCase<T1, T2, T3, T4>
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: ProgressionRepository + Send + Sync,
+    T4: AuditLogRepository + Send + Sync,
 {
     mission_operation_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
+    progression_repository: Arc<T3>,
+    audit_log_repository: Arc<T4>,
+    max_crew_per_mission: u32,
 }
 
-impl<T1, T2> MissionOperationUseCase<T1, T2>
+impl<T1, T2, T3, T4> MissionOperationUseCase<T1, T2, T3, T4>
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: ProgressionRepository + Send + Sync,
+    T4: AuditLogRepository + Send + Sync,
 {
-    pub fn new(mission_operation_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(
+        mission_operation_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        progression_repository: Arc<T3>,
+        audit_log_repository: Arc<T4>,
+        max_crew_per_mission: u32,
+    ) -> Self {
         Self {
             mission_operation_repository,
             mission_viewing_repository,
+            progression_repository,
+            audit_log_repository,
+            max_crew_per_mission,
         }
     }
 
-    pub async fn in_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    pub async fn in_progress(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        audit: AuditContext,
+    ) -> Result<i32> {
         let mission = self
             .mission_viewing_repository
             .view_detail(mission_id)
@@ -52,19 +83,13 @@ where
         }
 
         // If Failed, check if it was "Ended Failed" (has deadline or low crew count)
-        if mission.status == MissionStatuses::Failed.to_string() {
-            if mission.deadline.is_some() {
-                return Err(anyhow::anyhow!(
-                    "Cannot restart a failed mission that has ended"
-                ));
-            }
+        if mission.status == MissionStatuses::Failed.to_string() && mission.deadline.is_some() {
+            return Err(anyhow::anyhow!(
+                "Cannot restart a failed mission that has ended"
+            ));
         }
 
-        let max_crew_per_mission: u32 = std::env::var("MAX_CREW_PER_MISSION")
-            .unwrap_or_else(|_| "5".to_string())
-            .parse()?;
-
-        if crew_count >= max_crew_per_mission {
+        if crew_count >= self.max_crew_per_mission {
             return Err(anyhow::anyhow!("Mission crew is full"));
         }
 
@@ -87,10 +112,18 @@ where
             .to_progress(mission_id, chief_id, deadline)
             .await?;
 
+        self.record_transition(&audit, chief_id, AuditAction::MissionStarted, &mission)
+            .await;
+
         Ok(result)
     }
 
-    pub async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    pub async fn to_completed(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        audit: AuditContext,
+    ) -> Result<i32> {
         let mission = self
             .mission_viewing_repository
             .view_detail(mission_id)
@@ -108,14 +141,44 @@ where
             ));
         }
 
+        if mission.require_objectives && mission.progress.is_some_and(|progress| progress < 100) {
+            return Err(anyhow::anyhow!(
+                "All objectives must be completed before completing the mission"
+            ));
+        }
+
+        let crew_count = self
+            .mission_viewing_repository
+            .crew_counting(mission_id)
+            .await?;
+
+        let xp = mission_xp(crew_count, mission.duration);
         let result = self
             .mission_operation_repository
-            .to_completed(mission_id, chief_id)
+            .to_completed(mission_id, chief_id, xp)
             .await?;
+
+        self.record_transition(&audit, chief_id, AuditAction::MissionCompleted, &mission)
+            .await;
+
+        // XP is already awarded with the status change. Achievements are derived from stats and
+        // re-evaluated every time the brawler finishes a mission, so a failure here is logged.
+        if let Err(e) = self.evaluate_achievements(mission_id).await {
+            warn!(
+                "Failed to evaluate achievements for mission {}: {}",
+                mission_id, e
+            );
+        }
+
         Ok(result)
     }
 
-    pub async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    pub async fn to_failed(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        audit: AuditContext,
+    ) -> Result<i32> {
         let mission = self
             .mission_viewing_repository
             .view_detail(mission_id)
@@ -135,6 +198,68 @@ where
             .mission_operation_repository
             .to_failed(mission_id, chief_id)
             .await?;
+
+        self.record_transition(&audit, chief_id, AuditAction::MissionFailed, &mission)
+            .await;
+
+        if let Err(e) = self.evaluate_achievements(mission_id).await {
+            warn!(
+                "Failed to evaluate achievements for mission {}: {}",
+                mission_id, e
+            );
+        }
+
         Ok(result)
     }
+
+    /// Audits a status change, diffing the mission as it was against how it reads now.
+    async fn record_transition(
+        &self,
+        audit: &AuditContext,
+        chief_id: i32,
+        action: AuditAction,
+        mission: &MissionModel,
+    ) {
+        let (before, after) = match self
+            .mission_viewing_repository
+            .view_detail(mission.id)
+            .await
+        {
+            Ok(after) => diff(mission_snapshot(mission), mission_snapshot(&after)),
+            Err(_) => (Some(mission_snapshot(mission)), None),
+        };
+
+        audit_log::record(
+            self.audit_log_repository.as_ref(),
+            audit,
+            chief_id,
+            action,
+            AuditTarget::Mission(mission.id),
+            before,
+            after,
+        )
+        .await;
+    }
+
+    /// Re-evaluates the achievements of everyone on the mission.
+    async fn evaluate_achievements(&self, mission_id: i32) -> Result<()> {
+        let participants = self
+            .progression_repository
+            .mission_participants(mission_id)
+            .await?;
+
+        for brawler_id in participants {
+            let stats = self.progression_repository.get_stats(brawler_id).await?;
+            let unlocked = self
+                .progression_repository
+                .unlock_achievements(brawler_id, Achievement::evaluate(&stats))
+                .await?;
+
+            if !unlocked.is_empty() {
+                info!("Brawler {} unlocked {:?}", brawler_id, unlocked);
+            }
+        }
+
+        Ok(())
+    }
 }
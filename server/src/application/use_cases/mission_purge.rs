@@ -0,0 +1,76 @@
+use anyhow::Result;
+use std::sync::Arc;
+use tracing::warn;
+
+use crate::domain::repositories::{
+    mission_attachments::MissionAttachmentRepository,
+    mission_management::MissionManagementRepository,
+};
+
+pub struct MissionPurgeUseCase<T1, T2>
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionAttachmentRepository + Send + Sync,
+{
+    mission_management_repository: Arc<T1>,
+    mission_attachment_repository: Arc<T2>,
+}
+
+impl<T1, T2> MissionPurgeUseCase<T1, T2>
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionAttachmentRepository + Send + Sync,
+{
+    pub fn new(
+        mission_management_repository: Arc<T1>,
+        mission_attachment_repository: Arc<T2>,
+    ) -> Self {
+        Self {
+            mission_management_repository,
+            mission_attachment_repository,
+        }
+    }
+
+    pub async fn purge_expired(&self, retention_days: i64) -> Result<Vec<i32>> {
+        let deleted_before =
+            chrono::Utc::now().naive_utc() - chrono::Duration::days(retention_days);
+
+        let expired_ids = self
+            .mission_management_repository
+            .gets_expired(deleted_before)
+            .await?;
+
+        // Missions whose images could not be removed from storage are kept for the next run.
+        let mut mission_ids = Vec::with_capacity(expired_ids.len());
+        for mission_id in expired_ids {
+            // Storage is slow; a mission restored in the meantime must keep its images.
+            if !self
+                .mission_management_repository
+                .is_expired(mission_id, deleted_before)
+                .await?
+            {
+                continue;
+            }
+
+            match self
+                .mission_attachment_repository
+                .remove_all(mission_id)
+                .await
+            {
+                Ok(_) => mission_ids.push(mission_id),
+                Err(e) => warn!(
+                    "Failed to remove attachments of mission {}: {}",
+                    mission_id, e
+                ),
+            }
+        }
+
+        if mission_ids.is_empty() {
+            return Ok(mission_ids);
+        }
+
+        self.mission_management_repository
+            .purge(mission_ids, deleted_before)
+            .await
+    }
+}
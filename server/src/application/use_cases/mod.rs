@@ -1,7 +1,17 @@
+pub mod audit_log;
 pub mod authentication;
+pub mod brawler_ratings;
 pub mod brawlers;
 pub mod crew_operation;
 pub mod dashboard;
+pub mod leaderboard;
+pub mod mission_attachments;
+pub mod mission_comments;
 pub mod mission_management;
+pub mod mission_objectives;
 pub mod mission_operation;
+pub mod mission_purge;
 pub mod mission_viewing;
+pub mod oidc_login;
+pub mod password_reset;
+pub mod personal_access_tokens;
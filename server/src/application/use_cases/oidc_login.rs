@@ -0,0 +1,255 @@
+use anyhow::Result;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use tracing::warn;
+
+use crate::{
+    domain::{
+        entities::brawlers::RegisterBrawlerEntity,
+        repositories::{
+            brawler_identities::BrawlerIdentityRepository, brawlers::BrawlerRepository,
+            identity_provider::IdentityProvider,
+        },
+        value_objects::{
+            brawler_model::MAX_DISPLAY_NAME_LENGTH,
+            credentials::{self, MAX_USERNAME_LENGTH, MIN_USERNAME_LENGTH},
+            external_identity::{
+                AuthorizationRequest, ExternalIdentity, IDENTITY_NOT_LINKED, INVALID_OIDC_STATE,
+                OIDC_SIGN_IN_REFUSED, OidcCallbackQuery,
+            },
+        },
+    },
+    infrastructure::{
+        argon2,
+        jwt::{
+            generate_token,
+            jwt_model::{OidcFlowClaims, Passport},
+            keys::JwtKeys,
+            verify_scoped_token,
+        },
+    },
+};
+
+/// `aud` of the flow token, which keeps it from ever passing as an access token.
+const OIDC_FLOW_AUDIENCE: &str = "oidc-flow";
+/// How long the user has to finish signing in at the provider.
+pub const OIDC_FLOW_TTL_SECONDS: i64 = 600;
+/// Attempts at a free username before registration gives up.
+const USERNAME_ATTEMPTS: usize = 5;
+
+/// Where to send the browser, plus the token the caller keeps until the callback.
+pub struct OidcFlowStart {
+    pub authorization_url: String,
+    pub flow_token: String,
+}
+
+pub struct OidcLoginUseCase<T1, T2>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: BrawlerIdentityRepository + Send + Sync,
+{
+    brawler_repository: Arc<T1>,
+    brawler_identity_repository: Arc<T2>,
+    identity_provider: Arc<dyn IdentityProvider>,
+    jwt_keys: Arc<JwtKeys>,
+    auto_register: bool,
+}
+
+impl<T1, T2> OidcLoginUseCase<T1, T2>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: BrawlerIdentityRepository + Send + Sync,
+{
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        brawler_identity_repository: Arc<T2>,
+        identity_provider: Arc<dyn IdentityProvider>,
+        jwt_keys: Arc<JwtKeys>,
+        auto_register: bool,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            brawler_identity_repository,
+            identity_provider,
+            jwt_keys,
+            auto_register,
+        }
+    }
+
+    /// Starts a login, or with `link_brawler_id` links the identity to that brawler instead.
+    pub async fn start(&self, link_brawler_id: Option<i32>) -> Result<OidcFlowStart> {
+        let code_verifier = random_token();
+        let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+
+        let claims = OidcFlowClaims {
+            aud: OIDC_FLOW_AUDIENCE.to_string(),
+            exp: (chrono::Utc::now() + chrono::Duration::seconds(OIDC_FLOW_TTL_SECONDS)).timestamp()
+                as usize,
+            state: random_token(),
+            nonce: random_token(),
+            code_verifier,
+            link_brawler_id,
+        };
+
+        let authorization_url = self
+            .identity_provider
+            .authorization_url(AuthorizationRequest {
+                state: claims.state.clone(),
+                nonce: claims.nonce.clone(),
+                code_challenge,
+            })
+            .await?;
+
+        Ok(OidcFlowStart {
+            authorization_url,
+            flow_token: generate_token(&self.jwt_keys, &claims)?,
+        })
+    }
+
+    pub async fn finish(
+        &self,
+        query: OidcCallbackQuery,
+        flow_token: Option<String>,
+    ) -> Result<Passport> {
+        if let Some(error) = query.error {
+            warn!(
+                "Identity provider refused sign-in: {} {}",
+                error,
+                query.error_description.unwrap_or_default()
+            );
+            return Err(anyhow::anyhow!(OIDC_SIGN_IN_REFUSED));
+        }
+
+        let flow = flow_token
+            .and_then(|token| {
+                verify_scoped_token::<OidcFlowClaims>(&self.jwt_keys, &token, OIDC_FLOW_AUDIENCE)
+                    .ok()
+            })
+            .filter(|flow| query.state.as_deref() == Some(flow.state.as_str()))
+            .ok_or_else(|| anyhow::anyhow!(INVALID_OIDC_STATE))?;
+        let code = query
+            .code
+            .ok_or_else(|| anyhow::anyhow!(INVALID_OIDC_STATE))?;
+
+        let identity = self
+            .identity_provider
+            .exchange_code(code, flow.code_verifier, flow.nonce)
+            .await?;
+
+        let brawler_id = match flow.link_brawler_id {
+            Some(brawler_id) => {
+                self.brawler_identity_repository
+                    .link(brawler_id, identity)
+                    .await?;
+                brawler_id
+            }
+            None => match self
+                .brawler_identity_repository
+                .login(identity.clone())
+                .await?
+            {
+                Some(brawler_id) => brawler_id,
+                None if self.auto_register => self.register(identity).await?,
+                None => return Err(anyhow::anyhow!(IDENTITY_NOT_LINKED)),
+            },
+        };
+
+        let brawler = self.brawler_repository.find_by_id(brawler_id).await?;
+        let achievements = self
+            .brawler_repository
+            .get_achievements(brawler_id)
+            .await?
+            .into_iter()
+            .map(|unlocked| unlocked.achievement)
+            .collect();
+
+        Passport::new(
+            &self.jwt_keys,
+            brawler.id,
+            brawler.display_name,
+            brawler.avatar_url,
+            brawler.xp,
+            achievements,
+        )
+    }
+
+    /// New brawlers get a username derived from the identity and a random password nobody
+    /// knows, so only the provider can sign them in.
+    async fn register(&self, identity: ExternalIdentity) -> Result<i32> {
+        let base_username = username_from(&identity);
+
+        let mut username = None;
+        for attempt in 0..USERNAME_ATTEMPTS {
+            let candidate = if attempt == 0 {
+                base_username.clone()
+            } else {
+                format!(
+                    "{}-{}",
+                    base_username,
+                    hex::encode(rand::random::<[u8; 2]>())
+                )
+            };
+            if self
+                .brawler_repository
+                .find_by_username(candidate.clone())
+                .await?
+                .is_none()
+            {
+                username = Some(candidate);
+                break;
+            }
+        }
+        let username =
+            username.ok_or_else(|| anyhow::anyhow!("Could not find a free username!"))?;
+
+        let display_name = identity
+            .name
+            .clone()
+            .or_else(|| identity.preferred_username.clone())
+            .map(|name| name.trim().chars().take(MAX_DISPLAY_NAME_LENGTH).collect())
+            .filter(|name: &String| !name.is_empty())
+            .unwrap_or_else(|| username.clone());
+
+        let register_entity = RegisterBrawlerEntity {
+            username,
+            password: argon2::hash(random_token())?,
+            display_name,
+            email: None,
+        };
+
+        self.brawler_identity_repository
+            .register(register_entity, identity)
+            .await
+    }
+}
+
+fn random_token() -> String {
+    URL_SAFE_NO_PAD.encode(rand::random::<[u8; 32]>())
+}
+
+/// Keeps what `credentials::validate_username` allows from the provider's username or email,
+/// leaving room for a `-xxxx` suffix on collisions.
+fn username_from(identity: &ExternalIdentity) -> String {
+    let source = identity
+        .preferred_username
+        .as_deref()
+        .or(identity.email.as_deref())
+        .unwrap_or_default();
+    let source = source.split('@').next().unwrap_or_default();
+
+    let username: String = source
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+        .skip_while(|c| !c.is_ascii_alphanumeric())
+        .take(MAX_USERNAME_LENGTH - 5)
+        .collect();
+
+    if username.chars().count() < MIN_USERNAME_LENGTH
+        || credentials::validate_username(&username).is_err()
+    {
+        return "brawler".to_string();
+    }
+
+    username
+}
@@ -0,0 +1,123 @@
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use tracing::info;
+
+use crate::{
+    config::config_model::PasswordResetEnv,
+    domain::{
+        repositories::{
+            brawlers::BrawlerRepository, mailer::Mailer, password_reset::PasswordResetRepository,
+        },
+        value_objects::{
+            credentials::{self, CredentialsError},
+            mail_message::MailMessage,
+            validation::ValidationErrors,
+        },
+    },
+    infrastructure::argon2::hash,
+};
+
+pub struct PasswordResetUseCase<T1, T2>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: PasswordResetRepository + Send + Sync,
+{
+    brawler_repository: Arc<T1>,
+    password_reset_repository: Arc<T2>,
+    mailer: Arc<dyn Mailer>,
+    password_reset_env: PasswordResetEnv,
+}
+
+impl<T1, T2> PasswordResetUseCase<T1, T2>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: PasswordResetRepository + Send + Sync,
+{
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        password_reset_repository: Arc<T2>,
+        mailer: Arc<dyn Mailer>,
+        password_reset_env: PasswordResetEnv,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            password_reset_repository,
+            mailer,
+            password_reset_env,
+        }
+    }
+
+    /// Mails a reset link to the brawler's address. Unknown usernames and brawlers without an
+    /// email are ignored silently so the endpoint can't be used to probe for accounts.
+    pub async fn request(&self, username: String) -> Result<()> {
+        let Some(brawler) = self.brawler_repository.find_by_username(username).await? else {
+            return Ok(());
+        };
+        let Some(email) = brawler.email else {
+            info!(
+                "Password reset requested for brawler {} without an email",
+                brawler.id
+            );
+            return Ok(());
+        };
+
+        let token = hex::encode(rand::random::<[u8; 32]>());
+        let expires_at = chrono::Utc::now().naive_utc()
+            + chrono::Duration::minutes(self.password_reset_env.ttl_minutes);
+
+        self.password_reset_repository
+            .issue(brawler.id, hash_token(&token), expires_at)
+            .await?;
+
+        self.mailer
+            .send(MailMessage {
+                to: email,
+                subject: "Reset your password".to_string(),
+                body: format!(
+                    "Hi {},\n\nOpen the link below to choose a new password. It expires in {} minutes.\n\n{}?token={}\n\nIf you didn't ask for this, you can ignore this email.",
+                    brawler.display_name,
+                    self.password_reset_env.ttl_minutes,
+                    self.password_reset_env.url,
+                    token
+                ),
+            })
+            .await
+    }
+
+    pub async fn confirm(&self, token: String, new_password: String) -> Result<()> {
+        let token_hash = hash_token(token.trim());
+
+        let Some(brawler_id) = self
+            .password_reset_repository
+            .find_brawler_id(token_hash.clone())
+            .await?
+        else {
+            return Err(CredentialsError::InvalidResetToken.into());
+        };
+
+        let brawler = self.brawler_repository.find_by_id(brawler_id).await?;
+        let mut errors = ValidationErrors::new();
+        errors.check(
+            "new_password",
+            credentials::validate_password(&new_password, &brawler.username),
+        );
+        errors.into_result()?;
+
+        let hashed_password = hash(new_password)?;
+        if !self
+            .password_reset_repository
+            .consume(token_hash, hashed_password)
+            .await?
+        {
+            return Err(CredentialsError::InvalidResetToken.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Only the SHA-256 of a token is stored, so a leaked table can't be used to reset passwords.
+fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
@@ -0,0 +1,139 @@
+use anyhow::Result;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use std::sync::Arc;
+use tracing::warn;
+
+use crate::{
+    domain::{
+        entities::personal_access_tokens::AddPersonalAccessTokenEntity,
+        repositories::personal_access_tokens::PersonalAccessTokenRepository,
+        value_objects::personal_access_tokens::{
+            CreatePersonalAccessTokenModel, CreatedPersonalAccessTokenModel,
+            PERSONAL_ACCESS_TOKEN_PREFIX, PersonalAccessTokenGrant, PersonalAccessTokenModel,
+            TOKEN_NOT_FOUND, TokenScope, parse_scopes,
+        },
+    },
+    infrastructure::argon2,
+};
+
+pub struct PersonalAccessTokensUseCase<T>
+where
+    T: PersonalAccessTokenRepository + Send + Sync,
+{
+    personal_access_token_repository: Arc<T>,
+}
+
+impl<T> PersonalAccessTokensUseCase<T>
+where
+    T: PersonalAccessTokenRepository + Send + Sync,
+{
+    pub fn new(personal_access_token_repository: Arc<T>) -> Self {
+        Self {
+            personal_access_token_repository,
+        }
+    }
+
+    /// Mints a token of the form `lolpat_<prefix>_<secret>`. The prefix is stored in the clear
+    /// to find the row; the secret only as an argon2 hash, so this is the one time it is seen.
+    pub async fn create(
+        &self,
+        brawler_id: i32,
+        model: CreatePersonalAccessTokenModel,
+    ) -> Result<CreatedPersonalAccessTokenModel> {
+        let token_prefix = hex::encode(rand::random::<[u8; 8]>());
+        let secret = URL_SAFE_NO_PAD.encode(rand::random::<[u8; 32]>());
+
+        let mut scopes: Vec<TokenScope> = Vec::new();
+        for scope in model.scopes {
+            if !scopes.contains(&scope) {
+                scopes.push(scope);
+            }
+        }
+
+        let add_entity = AddPersonalAccessTokenEntity {
+            brawler_id,
+            name: model.name.trim().to_string(),
+            token_prefix: token_prefix.clone(),
+            token_hash: argon2::hash(secret.clone())?,
+            scopes: scopes.iter().map(|scope| scope.to_string()).collect(),
+            expires_at: model
+                .expires_in_days
+                .map(|days| (chrono::Utc::now() + chrono::Duration::days(days)).naive_utc()),
+        };
+
+        let entity = self
+            .personal_access_token_repository
+            .create(add_entity)
+            .await?;
+
+        Ok(CreatedPersonalAccessTokenModel {
+            details: entity.into(),
+            token: format!(
+                "{}{}_{}",
+                PERSONAL_ACCESS_TOKEN_PREFIX, token_prefix, secret
+            ),
+        })
+    }
+
+    pub async fn list(&self, brawler_id: i32) -> Result<Vec<PersonalAccessTokenModel>> {
+        let tokens = self
+            .personal_access_token_repository
+            .list(brawler_id)
+            .await?;
+
+        Ok(tokens.into_iter().map(Into::into).collect())
+    }
+
+    pub async fn revoke(&self, brawler_id: i32, token_id: i32) -> Result<()> {
+        if !self
+            .personal_access_token_repository
+            .revoke(brawler_id, token_id)
+            .await?
+        {
+            return Err(anyhow::anyhow!(TOKEN_NOT_FOUND));
+        }
+
+        Ok(())
+    }
+
+    /// The grant of a token that exists, isn't revoked or expired, and whose secret matches;
+    /// `None` for anything else.
+    pub async fn authenticate(&self, token: &str) -> Result<Option<PersonalAccessTokenGrant>> {
+        let Some((token_prefix, secret)) = token
+            .strip_prefix(PERSONAL_ACCESS_TOKEN_PREFIX)
+            .and_then(|token| token.split_once('_'))
+        else {
+            return Ok(None);
+        };
+
+        let Some(entity) = self
+            .personal_access_token_repository
+            .find_active_by_prefix(token_prefix.to_string())
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        // Runs on every request made with a token; keep the slow hash off the async workers.
+        let secret = secret.to_string();
+        let token_hash = entity.token_hash.clone();
+        let matches =
+            tokio::task::spawn_blocking(move || argon2::verify(secret, token_hash)).await??;
+        if !matches {
+            warn!(
+                "Personal access token {} was presented with a wrong secret",
+                entity.id
+            );
+            return Ok(None);
+        }
+
+        self.personal_access_token_repository
+            .touch(entity.id)
+            .await?;
+
+        Ok(Some(PersonalAccessTokenGrant {
+            brawler_id: entity.brawler_id,
+            scopes: parse_scopes(&entity.scopes),
+        }))
+    }
+}
@@ -1,85 +1,457 @@
-use std::env;
+use std::{env, net::IpAddr, path::PathBuf};
 
 use anyhow::Result;
-use tracing::error;
+use ipnet::IpNet;
 
 use crate::config::{
-    config_model::{CloudinaryEnv, Database, DotEnvyConfig, JwtEnv, Server},
+    config_model::{
+        AdminEnv, AppConfig, CloudinaryEnv, CommentEnv, ConfigReport, CookieSameSite, CorsEnv,
+        CrewEnv, Database, FeaturesEnv, HttpMailerEnv, ImageStorageBackend, ImageStorageEnv,
+        JwtAlgorithm, JwtEnv, LeaderboardEnv, LoginThrottleEnv, MailerBackend, MailerEnv,
+        MissionTrash, OidcEnv, PasswordResetEnv, RateLimit, RateLimitEnv, Server, SessionCookieEnv,
+    },
+    settings::Settings,
     stage::Stage,
 };
 
-pub fn load() -> Result<DotEnvyConfig> {
+/// Shortest JWT secret accepted when running as Prod.
+const MIN_PRODUCTION_SECRET_LENGTH: usize = 32;
+
+pub fn load() -> Result<AppConfig> {
     dotenvy::dotenv().ok();
 
+    // The stage picks the config file, so it can only come from the environment.
+    let stage = load_stage()?;
+    let config_dir = env::var("CONFIG_DIR").unwrap_or_else(|_| "config".to_string());
+    let file = PathBuf::from(config_dir).join(format!("{}.toml", stage.profile()));
+    let mut settings = Settings::from_file(&file)?;
+
+    // Check PORT first (for Render), then fall back to SERVER_PORT
+    let port_key = if settings.is_set("PORT") {
+        "PORT"
+    } else {
+        "SERVER_PORT"
+    };
     let server = Server {
-        // Check PORT first (for Render), then fall back to SERVER_PORT
-        port: env::var("PORT")
-            .or_else(|_| env::var("SERVER_PORT"))
-            .expect("PORT or SERVER_PORT must be set")
-            .parse()?,
-        body_limit: env::var("SERVER_BODY_LIMIT")
-            .unwrap_or_else(|_| "10".to_string())
-            .parse()?,
-        timeout: env::var("SERVER_TIMEOUT")
-            .unwrap_or_else(|_| "30".to_string())
-            .parse()?,
+        port: settings.parse(port_key, "8000"),
+        body_limit: settings.parse("SERVER_BODY_LIMIT", "10"),
+        timeout: settings.parse("SERVER_TIMEOUT", "30"),
+        trusted_proxies: load_trusted_proxies(&mut settings),
     };
 
     let database = Database {
-        url: env::var("DATABASE_URL")
-            .expect("DATABASE_URL is valid")
-            .parse()?,
+        url: settings.required("DATABASE_URL"),
+    };
+
+    let jwt = load_jwt_env(&mut settings);
+
+    let mission_trash = MissionTrash {
+        retention_days: settings.parse("MISSION_RETENTION_DAYS", "30"),
+        purge_interval: settings.parse("MISSION_PURGE_INTERVAL", "3600"),
+    };
+
+    let crew = CrewEnv {
+        max_per_mission: settings.parse("MAX_CREW_PER_MISSION", "5"),
+    };
+
+    let mut image_storage = ImageStorageEnv {
+        backend: settings.parse("IMAGE_STORAGE", "cloudinary"),
+        local_dir: settings.string("LOCAL_STORAGE_DIR", "uploads"),
+        local_url: settings.string("LOCAL_STORAGE_URL", "/uploads"),
+        cloudinary: None,
     };
 
-    let secret = env::var("JWT_USER_SECRET")
-        .expect("SECRET is valid")
-        .parse()?;
+    if image_storage.backend == ImageStorageBackend::Cloudinary {
+        image_storage.cloudinary = Some(load_cloudinary_env(&mut settings));
+    }
+
+    let mut mailer = MailerEnv {
+        backend: settings.parse("MAILER", "log"),
+        dir: settings.string("MAILER_DIR", "mail"),
+        http: None,
+    };
+
+    if mailer.backend == MailerBackend::Http {
+        mailer.http = Some(load_http_mailer_env(&mut settings));
+    }
+
+    let password_reset = PasswordResetEnv {
+        ttl_minutes: settings.parse("PASSWORD_RESET_TTL", "30"),
+        url: settings.string("PASSWORD_RESET_URL", "http://localhost:4200/reset-password"),
+    };
+
+    let rate_limit = RateLimitEnv {
+        default: parse_rate_limit(&mut settings, "RATE_LIMIT_DEFAULT", "300/60"),
+        auth: parse_rate_limit(&mut settings, "RATE_LIMIT_AUTH", "20/60"),
+        crew: parse_rate_limit(&mut settings, "RATE_LIMIT_CREW", "30/60"),
+        uploads: parse_rate_limit(&mut settings, "RATE_LIMIT_UPLOADS", "10/60"),
+    };
+
+    let comment = CommentEnv {
+        public_when_open: settings.parse("MISSION_COMMENTS_PUBLIC_WHEN_OPEN", "true"),
+    };
 
-    let config = DotEnvyConfig {
+    let leaderboard = LeaderboardEnv {
+        refresh_interval: settings.parse("LEADERBOARD_REFRESH_INTERVAL", "300"),
+        min_sample: settings.parse("LEADERBOARD_MIN_SAMPLE", "5"),
+    };
+
+    let login_throttle = load_login_throttle_env(&mut settings);
+
+    let cors = CorsEnv {
+        allowed_origins: settings
+            .string("CORS_ALLOWED_ORIGINS", "")
+            .split(',')
+            .map(|origin| origin.trim().to_string())
+            .filter(|origin| !origin.is_empty())
+            .collect(),
+        allow_credentials: settings.parse(
+            "CORS_ALLOW_CREDENTIALS",
+            if stage == Stage::Production {
+                "true"
+            } else {
+                "false"
+            },
+        ),
+    };
+
+    let session_cookie = SessionCookieEnv {
+        enabled: settings.parse("SESSION_COOKIE_ENABLED", "false"),
+        secure: settings.parse(
+            "SESSION_COOKIE_SECURE",
+            if stage == Stage::Production {
+                "true"
+            } else {
+                "false"
+            },
+        ),
+        same_site: settings.parse("SESSION_COOKIE_SAME_SITE", "Lax"),
+    };
+
+    let admin = load_admin_env(&mut settings);
+
+    let features = FeaturesEnv {
+        mission_purge: settings.parse("FEATURE_MISSION_PURGE", "true"),
+        leaderboard_refresh: settings.parse("FEATURE_LEADERBOARD_REFRESH", "true"),
+        password_reset: settings.parse("FEATURE_PASSWORD_RESET", "true"),
+        oidc_login: settings.parse("FEATURE_OIDC_LOGIN", "false"),
+    };
+
+    let oidc = features.oidc_login.then(|| load_oidc_env(&mut settings));
+
+    let mut config = AppConfig {
+        report: ConfigReport {
+            file: file.exists().then_some(file),
+            settings: Vec::new(),
+        },
+        stage,
         server,
         database,
-        secret,
+        jwt,
+        mission_trash,
+        crew,
+        image_storage,
+        mailer,
+        password_reset,
+        rate_limit,
+        comment,
+        leaderboard,
+        login_throttle,
+        cors,
+        session_cookie,
+        admin,
+        features,
+        oidc,
     };
 
+    validate(&config, &mut settings);
+    config.report.settings = settings.finish()?;
+
     Ok(config)
 }
 
-pub fn get_stage() -> Stage {
-    dotenvy::dotenv().ok();
+/// `SERVER_TRUSTED_PROXIES` lists addresses or CIDR ranges, e.g. `10.0.0.0/8,127.0.0.1`.
+fn load_trusted_proxies(settings: &mut Settings) -> Vec<IpNet> {
+    let mut trusted_proxies = Vec::new();
+    for entry in settings.string("SERVER_TRUSTED_PROXIES", "").split(',') {
+        let entry = entry.trim();
+        if entry.is_empty() {
+            continue;
+        }
+        match entry
+            .parse::<IpNet>()
+            .or_else(|_| entry.parse::<IpAddr>().map(IpNet::from))
+        {
+            Ok(network) => trusted_proxies.push(network),
+            Err(_) => settings.invalid(format!(
+                "SERVER_TRUSTED_PROXIES must be a comma-separated list of IPs or CIDR ranges, got {:?}",
+                entry
+            )),
+        }
+    }
 
-    let stage_str = env::var("STAGE").unwrap_or("".to_string());
-    Stage::try_form(&stage_str).unwrap_or_default()
+    trusted_proxies
 }
 
-pub fn get_user_secret() -> Result<String> {
-    let dotenvy_env = match load() {
-        Ok(env) => env,
-        Err(e) => {
-            error!("Failed to load ENV: {}", e);
-            std::process::exit(1);
+fn load_admin_env(settings: &mut Settings) -> AdminEnv {
+    let mut brawler_ids = Vec::new();
+    for entry in settings.string("ADMIN_BRAWLER_IDS", "").split(',') {
+        let entry = entry.trim();
+        if entry.is_empty() {
+            continue;
         }
+        match entry.parse::<i32>() {
+            Ok(brawler_id) => brawler_ids.push(brawler_id),
+            Err(_) => settings.invalid(format!(
+                "ADMIN_BRAWLER_IDS must be a comma-separated list of brawler ids, got {:?}",
+                entry
+            )),
+        }
+    }
+
+    AdminEnv { brawler_ids }
+}
+
+/// Cross-field and range checks; runs after every value is read so they all get reported.
+fn validate(config: &AppConfig, settings: &mut Settings) {
+    if config.server.port == 0 {
+        settings.invalid("SERVER_PORT must not be 0");
+    }
+    if config.server.body_limit == 0 {
+        settings.invalid("SERVER_BODY_LIMIT must be at least 1 (MB)");
+    }
+    if config.server.timeout == 0 {
+        settings.invalid("SERVER_TIMEOUT must be at least 1 (seconds)");
+    }
+
+    let url = &config.database.url;
+    if !url.is_empty() && !url.starts_with("postgres://") && !url.starts_with("postgresql://") {
+        settings.invalid("DATABASE_URL must start with postgres:// or postgresql://");
+    }
+
+    if config.jwt.ttl < 1 {
+        settings.invalid("JWT_TTL must be at least 1 (days)");
+    }
+    if config.stage == Stage::Production
+        && !config.jwt.secret.is_empty()
+        && config.jwt.secret.len() < MIN_PRODUCTION_SECRET_LENGTH
+    {
+        settings.invalid(format!(
+            "JWT_USER_SECRET must be at least {} characters in Prod",
+            MIN_PRODUCTION_SECRET_LENGTH
+        ));
+    }
+
+    if config.mission_trash.retention_days < 1 {
+        settings.invalid("MISSION_RETENTION_DAYS must be at least 1");
+    }
+    if config.mission_trash.purge_interval == 0 {
+        settings.invalid("MISSION_PURGE_INTERVAL must be at least 1 (seconds)");
+    }
+
+    if config.crew.max_per_mission < 1 {
+        settings.invalid("MAX_CREW_PER_MISSION must be at least 1");
+    }
+
+    if !config.image_storage.local_url.starts_with('/') {
+        settings.invalid("LOCAL_STORAGE_URL must be a path starting with '/'");
+    }
+
+    if config.password_reset.ttl_minutes < 1 {
+        settings.invalid("PASSWORD_RESET_TTL must be at least 1 (minutes)");
+    }
+    if !config.password_reset.url.starts_with("http://")
+        && !config.password_reset.url.starts_with("https://")
+    {
+        settings.invalid("PASSWORD_RESET_URL must be an http(s) URL");
+    }
+    // Both keep the reset link (a live credential) on the server instead of sending it.
+    if config.stage == Stage::Production
+        && config.features.password_reset
+        && matches!(
+            config.mailer.backend,
+            MailerBackend::Log | MailerBackend::File
+        )
+    {
+        settings.invalid("MAILER=log|file are for development only; use MAILER=http in Prod");
+    }
+    if let Some(http) = &config.mailer.http
+        && !http.api_url.starts_with("http://")
+        && !http.api_url.starts_with("https://")
+    {
+        settings.invalid("MAILER_API_URL must be an http(s) URL");
+    }
+
+    if config.leaderboard.refresh_interval == 0 {
+        settings.invalid("LEADERBOARD_REFRESH_INTERVAL must be at least 1 (seconds)");
+    }
+    if config.leaderboard.min_sample < 0 {
+        settings.invalid("LEADERBOARD_MIN_SAMPLE must not be negative");
+    }
+
+    let throttle = &config.login_throttle;
+    if throttle.max_failures_per_user < 1 || throttle.max_failures_per_ip < 1 {
+        settings.invalid(
+            "LOGIN_MAX_FAILURES_PER_USER and LOGIN_MAX_FAILURES_PER_IP must be at least 1",
+        );
+    }
+    if throttle.lockout_base < 1 || throttle.lockout_max < throttle.lockout_base {
+        settings.invalid("LOGIN_LOCKOUT_BASE must be at least 1 and not above LOGIN_LOCKOUT_MAX");
+    }
+    if throttle.failure_window < 1 {
+        settings.invalid("LOGIN_FAILURE_WINDOW must be at least 1 (seconds)");
+    }
+
+    for origin in &config.cors.allowed_origins {
+        if !(origin.starts_with("http://") || origin.starts_with("https://"))
+            || origin.ends_with('/')
+        {
+            settings.invalid(format!(
+                "CORS_ALLOWED_ORIGINS entry '{}' must look like https://host[:port]",
+                origin
+            ));
+        }
+    }
+    if config.session_cookie.same_site == CookieSameSite::None && !config.session_cookie.secure {
+        settings.invalid("SESSION_COOKIE_SAME_SITE=None needs SESSION_COOKIE_SECURE=true");
+    }
+    if let Some(oidc) = &config.oidc {
+        if !oidc.issuer.starts_with("http://") && !oidc.issuer.starts_with("https://") {
+            settings.invalid("OIDC_ISSUER must be an http(s) URL");
+        }
+        if !oidc.redirect_url.starts_with("http://") && !oidc.redirect_url.starts_with("https://") {
+            settings.invalid("OIDC_REDIRECT_URL must be an http(s) URL");
+        }
+        if !oidc.frontend_url.starts_with('/')
+            && !oidc.frontend_url.starts_with("http://")
+            && !oidc.frontend_url.starts_with("https://")
+        {
+            settings
+                .invalid("OIDC_FRONTEND_URL must be an http(s) URL or a path starting with '/'");
+        }
+        // The callback is a browser navigation, so the session cookie is how it signs in.
+        if !config.session_cookie.enabled {
+            settings.invalid("FEATURE_OIDC_LOGIN needs SESSION_COOKIE_ENABLED=true");
+        }
+        if !oidc
+            .scopes
+            .split_whitespace()
+            .any(|scope| scope == "openid")
+        {
+            settings.invalid("OIDC_SCOPES must include openid");
+        }
+    }
+
+    // Browsers reject credentialed responses to a wildcard origin, hence the second check.
+    if config.stage == Stage::Production && config.cors.allowed_origins.is_empty() {
+        settings.invalid("CORS_ALLOWED_ORIGINS must list the allowed origins in Prod");
+    } else if config.cors.allow_credentials && config.cors.allowed_origins.is_empty() {
+        settings.invalid("CORS_ALLOW_CREDENTIALS requires CORS_ALLOWED_ORIGINS");
+    }
+}
+
+/// Reads `<requests>/<seconds>` (e.g. `20/60`), or `off` to disable the limit.
+fn parse_rate_limit(settings: &mut Settings, name: &str, default: &str) -> Option<RateLimit> {
+    let value = settings.string(name, default);
+    let value = value.trim();
+
+    if value.eq_ignore_ascii_case("off") {
+        return None;
+    }
+
+    let rate_limit = value.split_once('/').and_then(|(requests, per_seconds)| {
+        Some(RateLimit {
+            requests: requests.trim().parse().ok()?,
+            per_seconds: per_seconds.trim().parse().ok()?,
+        })
+    });
+
+    match rate_limit {
+        Some(rate_limit) if rate_limit.requests > 0 && rate_limit.per_seconds > 0 => {
+            Some(rate_limit)
+        }
+        _ => {
+            settings.invalid(format!(
+                "{} has an invalid value '{}': expected <requests>/<seconds> with positive values, or off",
+                name, value
+            ));
+            None
+        }
+    }
+}
+
+fn load_stage() -> Result<Stage> {
+    match env::var("STAGE") {
+        Ok(stage) if !stage.trim().is_empty() => Stage::try_form(stage.trim()),
+        _ => Ok(Stage::default()),
+    }
+}
+
+fn load_jwt_env(settings: &mut Settings) -> JwtEnv {
+    let algorithm = settings.parse("JWT_ALGORITHM", "HS256");
+
+    let (secret, keys_dir, signing_key_id) = match algorithm {
+        JwtAlgorithm::HS256 => (
+            settings.required("JWT_USER_SECRET"),
+            String::new(),
+            String::new(),
+        ),
+        JwtAlgorithm::RS256 | JwtAlgorithm::EdDSA => (
+            String::new(),
+            settings.string("JWT_KEYS_DIR", "keys"),
+            settings.required("JWT_SIGNING_KEY_ID"),
+        ),
     };
-    Ok(dotenvy_env.secret)
+
+    JwtEnv {
+        algorithm,
+        secret,
+        keys_dir,
+        signing_key_id,
+        ttl: settings.parse("JWT_TTL", "1"),
+    }
 }
 
-pub fn get_jwt_env() -> Result<JwtEnv> {
-    dotenvy::dotenv().ok();
-    Ok(JwtEnv {
-        secret: env::var("JWT_USER_SECRET")?,
-        ttl: env::var("JWT_TTL")?.parse::<i64>()?,
-    })
+fn load_cloudinary_env(settings: &mut Settings) -> CloudinaryEnv {
+    CloudinaryEnv {
+        cloud_name: settings.required("CLOUDINARY_CLOUD_NAME"),
+        api_key: settings.required("CLOUDINARY_API_KEY"),
+        api_secret: settings.required("CLOUDINARY_API_SECRET"),
+    }
 }
 
-pub fn get_cloudinary_env() -> Result<CloudinaryEnv> {
-    dotenvy::dotenv().ok();
+fn load_http_mailer_env(settings: &mut Settings) -> HttpMailerEnv {
+    HttpMailerEnv {
+        api_url: settings.string("MAILER_API_URL", "https://api.resend.com/emails"),
+        api_key: settings.required("MAILER_API_KEY"),
+        from: settings.required("MAILER_FROM"),
+    }
+}
 
-    let cloud_name = env::var("CLOUDINARY_CLOUD_NAME")?;
-    let api_key = env::var("CLOUDINARY_API_KEY")?;
-    let api_secret = env::var("CLOUDINARY_API_SECRET")?;
+fn load_oidc_env(settings: &mut Settings) -> OidcEnv {
+    OidcEnv {
+        issuer: settings
+            .required("OIDC_ISSUER")
+            .trim_end_matches('/')
+            .to_string(),
+        client_id: settings.required("OIDC_CLIENT_ID"),
+        client_secret: settings.required("OIDC_CLIENT_SECRET"),
+        redirect_url: settings.string(
+            "OIDC_REDIRECT_URL",
+            "http://localhost:8000/api/authentication/oidc/callback",
+        ),
+        frontend_url: settings.string("OIDC_FRONTEND_URL", "/"),
+        scopes: settings.string("OIDC_SCOPES", "openid profile email"),
+        auto_register: settings.parse("OIDC_AUTO_REGISTER", "true"),
+    }
+}
 
-    Ok(CloudinaryEnv {
-        cloud_name,
-        api_key,
-        api_secret,
-    })
+fn load_login_throttle_env(settings: &mut Settings) -> LoginThrottleEnv {
+    LoginThrottleEnv {
+        max_failures_per_user: settings.parse("LOGIN_MAX_FAILURES_PER_USER", "5"),
+        max_failures_per_ip: settings.parse("LOGIN_MAX_FAILURES_PER_IP", "20"),
+        lockout_base: settings.parse("LOGIN_LOCKOUT_BASE", "30"),
+        lockout_max: settings.parse("LOGIN_LOCKOUT_MAX", "900"),
+        failure_window: settings.parse("LOGIN_FAILURE_WINDOW", "900"),
+    }
 }
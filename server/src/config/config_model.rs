@@ -1,8 +1,16 @@
+use std::{path::PathBuf, str::FromStr};
+
+use ipnet::IpNet;
+
+use crate::config::{settings::ResolvedSetting, stage::Stage};
+
 #[derive(Debug, Clone)]
 pub struct Server {
     pub port: u16,
     pub body_limit: u64,
     pub timeout: u64,
+    /// Peers whose `Forwarded` / `X-Forwarded-For` headers are believed; empty trusts no one.
+    pub trusted_proxies: Vec<IpNet>,
 }
 
 #[derive(Debug, Clone)]
@@ -10,16 +18,77 @@ pub struct Database {
     pub url: String,
 }
 
+/// Effective configuration: built-in defaults, overridden by `<CONFIG_DIR>/<stage>.toml`,
+/// overridden by environment variables.
 #[derive(Debug, Clone)]
-pub struct DotEnvyConfig {
+pub struct AppConfig {
+    pub stage: Stage,
     pub server: Server,
     pub database: Database,
-    pub secret: String,
+    pub jwt: JwtEnv,
+    pub mission_trash: MissionTrash,
+    pub crew: CrewEnv,
+    pub image_storage: ImageStorageEnv,
+    pub mailer: MailerEnv,
+    pub password_reset: PasswordResetEnv,
+    pub rate_limit: RateLimitEnv,
+    pub comment: CommentEnv,
+    pub leaderboard: LeaderboardEnv,
+    pub login_throttle: LoginThrottleEnv,
+    pub cors: CorsEnv,
+    pub session_cookie: SessionCookieEnv,
+    pub admin: AdminEnv,
+    pub features: FeaturesEnv,
+    /// Only loaded when the OIDC login feature is on.
+    pub oidc: Option<OidcEnv>,
+    pub report: ConfigReport,
+}
+
+/// What was loaded and from where, for the startup log.
+#[derive(Debug, Clone)]
+pub struct ConfigReport {
+    /// `None` when the stage has no config file.
+    pub file: Option<PathBuf>,
+    pub settings: Vec<ResolvedSetting>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MissionTrash {
+    pub retention_days: i64,
+    pub purge_interval: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum JwtAlgorithm {
+    #[default]
+    HS256,
+    RS256,
+    EdDSA,
+}
+
+impl FromStr for JwtAlgorithm {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "HS256" => Ok(Self::HS256),
+            "RS256" => Ok(Self::RS256),
+            "EDDSA" => Ok(Self::EdDSA),
+            _ => Err(anyhow::anyhow!("expected HS256, RS256 or EdDSA")),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct JwtEnv {
+    pub algorithm: JwtAlgorithm,
+    /// HS256 only.
     pub secret: String,
+    /// RS256/EdDSA only: holds `<kid>.pem` and `<kid>.pub.pem` files.
+    pub keys_dir: String,
+    /// RS256/EdDSA only: kid of the key new tokens are signed with.
+    pub signing_key_id: String,
+    /// Days an access token stays valid.
     pub ttl: i64,
 }
 
@@ -30,3 +99,183 @@ pub struct CloudinaryEnv {
     pub api_secret: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct CrewEnv {
+    /// A mission with this many crew members can no longer be started.
+    pub max_per_mission: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct CommentEnv {
+    pub public_when_open: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct LeaderboardEnv {
+    pub refresh_interval: u64,
+    pub min_sample: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct LoginThrottleEnv {
+    pub max_failures_per_user: i32,
+    pub max_failures_per_ip: i32,
+    /// Seconds of the first lockout; every further failure doubles it.
+    pub lockout_base: i64,
+    pub lockout_max: i64,
+    /// Seconds after which earlier failures are forgotten.
+    pub failure_window: i64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum ImageStorageBackend {
+    #[default]
+    Cloudinary,
+    Local,
+}
+
+impl FromStr for ImageStorageBackend {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cloudinary" => Ok(Self::Cloudinary),
+            "local" => Ok(Self::Local),
+            _ => Err(anyhow::anyhow!("expected cloudinary or local")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ImageStorageEnv {
+    pub backend: ImageStorageBackend,
+    pub local_dir: String,
+    pub local_url: String,
+    /// Only loaded when the backend is Cloudinary.
+    pub cloudinary: Option<CloudinaryEnv>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum MailerBackend {
+    #[default]
+    Log,
+    File,
+    Http,
+}
+
+impl FromStr for MailerBackend {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "log" => Ok(Self::Log),
+            "file" => Ok(Self::File),
+            "http" => Ok(Self::Http),
+            _ => Err(anyhow::anyhow!("expected log, file or http")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpMailerEnv {
+    pub api_url: String,
+    pub api_key: String,
+    pub from: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MailerEnv {
+    pub backend: MailerBackend,
+    pub dir: String,
+    /// Only loaded when the backend is Http.
+    pub http: Option<HttpMailerEnv>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PasswordResetEnv {
+    pub ttl_minutes: i64,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimit {
+    pub requests: u32,
+    pub per_seconds: u64,
+}
+
+/// Per route group; `None` turns the group's limit off.
+#[derive(Debug, Clone)]
+pub struct RateLimitEnv {
+    pub default: Option<RateLimit>,
+    pub auth: Option<RateLimit>,
+    pub crew: Option<RateLimit>,
+    pub uploads: Option<RateLimit>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CorsEnv {
+    /// Empty allows any origin.
+    pub allowed_origins: Vec<String>,
+    pub allow_credentials: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum CookieSameSite {
+    Strict,
+    #[default]
+    Lax,
+    None,
+}
+
+impl FromStr for CookieSameSite {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "strict" => Ok(Self::Strict),
+            "lax" => Ok(Self::Lax),
+            "none" => Ok(Self::None),
+            _ => Err(anyhow::anyhow!("expected Strict, Lax or None")),
+        }
+    }
+}
+
+/// Cookie sessions for browser clients: login also sets the access token as an HttpOnly
+/// cookie, paired with a readable CSRF cookie for the double-submit check.
+#[derive(Debug, Clone)]
+pub struct SessionCookieEnv {
+    pub enabled: bool,
+    pub secure: bool,
+    pub same_site: CookieSameSite,
+}
+
+/// Brawlers allowed into the `/api/admin` endpoints.
+#[derive(Debug, Clone)]
+pub struct AdminEnv {
+    pub brawler_ids: Vec<i32>,
+}
+
+/// Switches for optional parts of the server; all on by default.
+#[derive(Debug, Clone)]
+pub struct FeaturesEnv {
+    pub mission_purge: bool,
+    pub leaderboard_refresh: bool,
+    pub password_reset: bool,
+    pub oidc_login: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct OidcEnv {
+    /// Discovery runs against `<issuer>/.well-known/openid-configuration`.
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: String,
+    /// Must match the redirect URI registered with the provider.
+    pub redirect_url: String,
+    /// Where the callback sends the browser afterwards, signed in through the session cookie
+    /// or with `?error=<code>`.
+    pub frontend_url: String,
+    pub scopes: String,
+    /// Whether an unknown identity gets a new brawler instead of being refused.
+    pub auto_register: bool,
+}
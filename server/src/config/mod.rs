@@ -1,3 +1,4 @@
 pub mod config_loader;
 pub mod config_model;
+pub mod settings;
 pub mod stage;
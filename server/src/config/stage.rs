@@ -25,7 +25,19 @@ impl Stage {
             "Local" => Ok(Self::Local),
             "Dev" => Ok(Self::Development),
             "Prod" => Ok(Self::Production),
-            _ => Err(anyhow::anyhow!("Invalid stage")),
+            _ => Err(anyhow::anyhow!(
+                "Invalid STAGE '{}': expected Local, Dev or Prod",
+                stage
+            )),
+        }
+    }
+
+    /// Name of the stage's config file, without the `.toml` extension.
+    pub fn profile(&self) -> &'static str {
+        match self {
+            Stage::Local => "local",
+            Stage::Development => "dev",
+            Stage::Production => "prod",
         }
     }
 }
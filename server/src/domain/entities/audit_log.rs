@@ -0,0 +1,34 @@
+use crate::infrastructure::database::schema::audit_log;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = audit_log)]
+pub struct AuditLogEntity {
+    pub id: i64,
+    pub actor_id: i32,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: i32,
+    pub mission_id: Option<i32>,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub request_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = audit_log)]
+pub struct AddAuditLogEntity {
+    pub actor_id: i32,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: i32,
+    pub mission_id: Option<i32>,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub request_id: Option<String>,
+    pub ip_address: Option<String>,
+}
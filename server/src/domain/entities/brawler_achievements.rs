@@ -0,0 +1,19 @@
+use crate::infrastructure::database::schema::brawler_achievements;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+#[derive(Debug, Clone, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = brawler_achievements)]
+pub struct BrawlerAchievementEntity {
+    pub brawler_id: i32,
+    pub achievement: String,
+    pub unlocked_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = brawler_achievements)]
+pub struct AddBrawlerAchievementEntity {
+    pub brawler_id: i32,
+    pub achievement: String,
+}
@@ -0,0 +1,11 @@
+use crate::infrastructure::database::schema::brawler_identities;
+use diesel::prelude::*;
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = brawler_identities)]
+pub struct AddBrawlerIdentityEntity {
+    pub brawler_id: i32,
+    pub issuer: String,
+    pub subject: String,
+    pub email: Option<String>,
+}
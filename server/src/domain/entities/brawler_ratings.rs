@@ -0,0 +1,27 @@
+use crate::infrastructure::database::schema::brawler_ratings;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = brawler_ratings)]
+pub struct BrawlerRatingEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub rater_id: i32,
+    pub ratee_id: i32,
+    pub score: i32,
+    pub comment: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = brawler_ratings)]
+pub struct AddBrawlerRatingEntity {
+    pub mission_id: i32,
+    pub rater_id: i32,
+    pub ratee_id: i32,
+    pub score: i32,
+    pub comment: Option<String>,
+}
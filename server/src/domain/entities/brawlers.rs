@@ -16,6 +16,10 @@ pub struct BrawlerEntity {
     pub display_name: String,
     pub avatar_url: Option<String>,
     pub avatar_public_id: Option<String>,
+    pub avatar_variants: Option<serde_json::Value>,
+    pub xp: i64,
+    #[serde(skip_serializing)]
+    pub email: Option<String>,
 }
 
 #[derive(Debug, Clone, Insertable)]
@@ -24,4 +28,5 @@ pub struct RegisterBrawlerEntity {
     pub username: String,
     pub password: String,
     pub display_name: String,
+    pub email: Option<String>,
 }
@@ -0,0 +1,10 @@
+use crate::infrastructure::database::schema::crew_departures;
+use diesel::prelude::*;
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = crew_departures)]
+pub struct AddCrewDepartureEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub reason: String,
+}
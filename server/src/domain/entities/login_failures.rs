@@ -0,0 +1,10 @@
+use crate::infrastructure::database::schema::login_failures;
+use diesel::prelude::*;
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = login_failures)]
+pub struct AddLoginFailureEntity {
+    pub username: String,
+    pub ip_address: Option<String>,
+    pub reason: String,
+}
@@ -0,0 +1,25 @@
+use crate::infrastructure::database::schema::mission_attachments;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_attachments)]
+pub struct MissionAttachmentEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub uploaded_by: i32,
+    pub url: String,
+    pub public_id: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_attachments)]
+pub struct AddMissionAttachmentEntity {
+    pub mission_id: i32,
+    pub uploaded_by: i32,
+    pub url: String,
+    pub public_id: String,
+}
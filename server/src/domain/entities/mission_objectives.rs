@@ -0,0 +1,33 @@
+use crate::infrastructure::database::schema::mission_objectives;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Serialize)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_objectives)]
+pub struct MissionObjectiveEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub position: i32,
+    pub title: String,
+    pub completed_at: Option<NaiveDateTime>,
+    pub completed_by: Option<i32>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_objectives)]
+pub struct AddMissionObjectiveEntity {
+    pub mission_id: i32,
+    pub position: i32,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = mission_objectives)]
+pub struct EditMissionObjectiveEntity {
+    pub position: Option<i32>,
+    pub title: Option<String>,
+}
@@ -17,6 +17,7 @@ pub struct MissionEntity {
     pub max_crew: i32,
     pub deadline: Option<NaiveDateTime>,
     pub duration: Option<i32>,
+    pub require_objectives: bool,
 }
 
 #[derive(Debug, Clone, Insertable)]
@@ -29,6 +30,7 @@ pub struct AddMissionEntity {
     pub max_crew: i32,
     pub deadline: Option<NaiveDateTime>,
     pub duration: Option<i32>,
+    pub require_objectives: bool,
 }
 
 #[derive(Debug, Clone, AsChangeset)]
@@ -41,4 +43,5 @@ pub struct EditMissionEntity {
     pub max_crew: Option<i32>,
     pub deadline: Option<NaiveDateTime>,
     pub duration: Option<i32>,
+    pub require_objectives: Option<bool>,
 }
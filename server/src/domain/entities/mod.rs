@@ -1,3 +1,14 @@
+pub mod audit_log;
+pub mod brawler_achievements;
+pub mod brawler_identities;
+pub mod brawler_ratings;
 pub mod brawlers;
+pub mod crew_departures;
 pub mod crew_memberships;
+pub mod login_failures;
+pub mod mission_attachments;
+pub mod mission_comments;
+pub mod mission_objectives;
 pub mod missions;
+pub mod personal_access_tokens;
+pub mod xp_awards;
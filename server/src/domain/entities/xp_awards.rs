@@ -0,0 +1,10 @@
+use crate::infrastructure::database::schema::xp_awards;
+use diesel::prelude::*;
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = xp_awards)]
+pub struct AddXpAwardEntity {
+    pub brawler_id: i32,
+    pub mission_id: i32,
+    pub amount: i32,
+}
@@ -0,0 +1,14 @@
+use crate::domain::{
+    entities::audit_log::{AddAuditLogEntity, AuditLogEntity},
+    value_objects::audit_log::AuditLogQuery,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait AuditLogRepository {
+    async fn record(&self, add_audit_log_entity: AddAuditLogEntity) -> Result<()>;
+    /// One page of the entries matching the query's filters, newest first.
+    async fn search(&self, query: &AuditLogQuery) -> Result<Vec<AuditLogEntity>>;
+    async fn counting(&self, query: &AuditLogQuery) -> Result<i64>;
+}
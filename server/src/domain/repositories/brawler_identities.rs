@@ -0,0 +1,19 @@
+use crate::domain::{
+    entities::brawlers::RegisterBrawlerEntity, value_objects::external_identity::ExternalIdentity,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait BrawlerIdentityRepository {
+    /// The brawler the identity is linked to, if any; bumps the identity's `last_login_at`.
+    async fn login(&self, identity: ExternalIdentity) -> Result<Option<i32>>;
+    /// Links the identity to the brawler. Linking it to the same brawler again is a no-op.
+    async fn link(&self, brawler_id: i32, identity: ExternalIdentity) -> Result<()>;
+    /// Creates the brawler together with its first identity.
+    async fn register(
+        &self,
+        register_brawler_entity: RegisterBrawlerEntity,
+        identity: ExternalIdentity,
+    ) -> Result<i32>;
+}
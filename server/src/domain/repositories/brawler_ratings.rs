@@ -0,0 +1,10 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::brawler_ratings::{AddBrawlerRatingEntity, BrawlerRatingEntity};
+
+#[async_trait]
+pub trait BrawlerRatingRepository {
+    async fn add(&self, add_rating_entity: AddBrawlerRatingEntity) -> Result<i32>;
+    async fn gets(&self, mission_id: i32) -> Result<Vec<BrawlerRatingEntity>>;
+}
@@ -2,8 +2,10 @@ use crate::domain::{
     entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
     value_objects::{
         base64_image::Base64Image,
+        brawler_model::BrawlerProfileStats,
         mission_model::MissionModel,
-        uploaded_image::{UploadImageOptions, UploadedImage},
+        progression::UnlockedAchievement,
+        uploaded_image::{UploadImageOptions, UploadedAvatar},
     },
 };
 use anyhow::Result;
@@ -12,15 +14,21 @@ use async_trait::async_trait;
 #[async_trait]
 pub trait BrawlerRepository {
     async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<i32>;
-    async fn find_by_username(&self, username: String) -> Result<BrawlerEntity>;
+    async fn find_by_username(&self, username: String) -> Result<Option<BrawlerEntity>>;
+    async fn find_by_id(&self, brawler_id: i32) -> Result<BrawlerEntity>;
+    async fn update_password(&self, brawler_id: i32, hashed_password: String) -> Result<()>;
     async fn upload_avatar(
         &self,
         brawler_id: i32,
-        base64_image: Base64Image,
+        variants: Vec<(u32, Base64Image)>,
         option: UploadImageOptions,
-    ) -> Result<UploadedImage>;
+    ) -> Result<UploadedAvatar>;
+    async fn remove_avatar(&self, brawler_id: i32) -> Result<()>;
     async fn get_brawlers_by_mission_id(&self, mission_id: i32) -> Result<Vec<BrawlerEntity>>;
     async fn update_profile(&self, brawler_id: i32, display_name: String) -> Result<()>;
     async fn crew_counting(&self, mission_id: i32) -> Result<u32>;
     async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>>;
+    async fn get_profile_stats(&self, brawler_id: i32) -> Result<Option<BrawlerProfileStats>>;
+    async fn get_achievements(&self, brawler_id: i32) -> Result<Vec<UnlockedAchievement>>;
+    async fn get_recent_missions(&self, brawler_id: i32, limit: i64) -> Result<Vec<MissionModel>>;
 }
@@ -1,9 +1,15 @@
-use crate::domain::entities::crew_memberships::CrewMemberShips;
+use crate::domain::{
+    entities::crew_memberships::CrewMemberShips, value_objects::departure_reasons::DepartureReasons,
+};
 use anyhow::Result;
 use async_trait::async_trait;
 
 #[async_trait]
 pub trait CrewOperationRepository {
     async fn join(&self, crew_memberships: CrewMemberShips) -> Result<()>;
-    async fn leave(&self, crew_memberships: CrewMemberShips) -> Result<()>;
+    async fn leave(
+        &self,
+        crew_memberships: CrewMemberShips,
+        reason: DepartureReasons,
+    ) -> Result<()>;
 }
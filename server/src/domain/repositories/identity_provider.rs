@@ -0,0 +1,16 @@
+use crate::domain::value_objects::external_identity::{AuthorizationRequest, ExternalIdentity};
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait IdentityProvider: Send + Sync {
+    /// Where to send the browser to sign in.
+    async fn authorization_url(&self, request: AuthorizationRequest) -> Result<String>;
+    /// Redeems the callback's code and verifies the returned ID token against `nonce`.
+    async fn exchange_code(
+        &self,
+        code: String,
+        code_verifier: String,
+        nonce: String,
+    ) -> Result<ExternalIdentity>;
+}
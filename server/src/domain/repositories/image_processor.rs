@@ -0,0 +1,12 @@
+use crate::domain::value_objects::base64_image::Base64Image;
+use anyhow::Result;
+
+/// CPU-bound, so callers run it on the blocking thread pool.
+pub trait ImageProcessor: Send + Sync {
+    /// One square image per requested size, cropped from the center of the original.
+    fn square_variants(
+        &self,
+        base64_image: &Base64Image,
+        sizes: &[u32],
+    ) -> Result<Vec<(u32, Base64Image)>>;
+}
@@ -0,0 +1,16 @@
+use crate::domain::value_objects::{
+    base64_image::Base64Image,
+    uploaded_image::{UploadImageOptions, UploadedImage},
+};
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ImageStorage: Send + Sync {
+    async fn upload(
+        &self,
+        base64_image: Base64Image,
+        option: UploadImageOptions,
+    ) -> Result<UploadedImage>;
+    async fn destroy(&self, public_id: String) -> Result<()>;
+}
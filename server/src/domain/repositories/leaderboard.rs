@@ -0,0 +1,18 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::value_objects::leaderboard::{
+    LeaderboardCategory, LeaderboardEntry, LeaderboardPeriod,
+};
+
+#[async_trait]
+pub trait LeaderboardRepository {
+    async fn ranking(
+        &self,
+        category: LeaderboardCategory,
+        period: LeaderboardPeriod,
+        min_sample: i64,
+        limit: i64,
+    ) -> Result<Vec<LeaderboardEntry>>;
+    async fn refresh(&self) -> Result<()>;
+}
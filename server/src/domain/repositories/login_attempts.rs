@@ -0,0 +1,21 @@
+use crate::domain::entities::login_failures::AddLoginFailureEntity;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+#[async_trait]
+pub trait LoginAttemptRepository {
+    /// Appends a row to the failed-login audit trail.
+    async fn add_failure(&self, add_login_failure_entity: AddLoginFailureEntity) -> Result<()>;
+    /// Counts one more consecutive failure for the key and returns the new count. Failures
+    /// from before `window_start` are forgotten first.
+    async fn register_failure(
+        &self,
+        throttle_key: String,
+        window_start: NaiveDateTime,
+    ) -> Result<i32>;
+    async fn lock(&self, throttle_key: String, locked_until: NaiveDateTime) -> Result<()>;
+    /// The latest lockout still running for any of the keys.
+    async fn locked_until(&self, throttle_keys: Vec<String>) -> Result<Option<NaiveDateTime>>;
+    async fn reset(&self, throttle_key: String) -> Result<()>;
+}
@@ -0,0 +1,8 @@
+use crate::domain::value_objects::mail_message::MailMessage;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, message: MailMessage) -> Result<()>;
+}
@@ -0,0 +1,22 @@
+use crate::domain::{
+    entities::mission_attachments::MissionAttachmentEntity,
+    value_objects::{base64_image::Base64Image, uploaded_image::UploadImageOptions},
+};
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait MissionAttachmentRepository {
+    async fn upload(
+        &self,
+        mission_id: i32,
+        uploaded_by: i32,
+        base64_image: Base64Image,
+        option: UploadImageOptions,
+    ) -> Result<MissionAttachmentEntity>;
+    async fn gets(&self, mission_id: i32) -> Result<Vec<MissionAttachmentEntity>>;
+    async fn find_by_id(&self, attachment_id: i32) -> Result<MissionAttachmentEntity>;
+    async fn counting(&self, mission_id: i32) -> Result<i64>;
+    async fn remove(&self, attachment_id: i32) -> Result<()>;
+    async fn remove_all(&self, mission_id: i32) -> Result<()>;
+}
@@ -0,0 +1,21 @@
+use crate::domain::{
+    entities::mission_comments::{AddMissionCommentEntity, MissionCommentEntity},
+    value_objects::mission_comment_model::MissionCommentModel,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait MissionCommentRepository {
+    async fn add(&self, add_comment_entity: AddMissionCommentEntity) -> Result<i32>;
+    async fn edit(&self, comment_id: i32, content: String) -> Result<i32>;
+    async fn remove(&self, comment_id: i32) -> Result<()>;
+    async fn find_by_id(&self, comment_id: i32) -> Result<MissionCommentEntity>;
+    async fn gets(
+        &self,
+        mission_id: i32,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<MissionCommentModel>>;
+    async fn counting(&self, mission_id: i32) -> Result<i64>;
+}
@@ -1,10 +1,18 @@
 use crate::domain::entities::missions::{AddMissionEntity, EditMissionEntity};
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 
 #[async_trait]
 pub trait MissionManagementRepository {
     async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32>;
     async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32>;
     async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()>;
+    async fn restore(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
+    async fn gets_expired(&self, deleted_before: NaiveDateTime) -> Result<Vec<i32>>;
+    async fn is_expired(&self, mission_id: i32, deleted_before: NaiveDateTime) -> Result<bool>;
+    /// Hard-deletes the missions among `mission_ids` that are still in the trash since before
+    /// `deleted_before`, with everything attached to them; returns the ids actually purged.
+    async fn purge(&self, mission_ids: Vec<i32>, deleted_before: NaiveDateTime)
+    -> Result<Vec<i32>>;
 }
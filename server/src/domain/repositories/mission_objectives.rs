@@ -0,0 +1,20 @@
+use crate::domain::entities::mission_objectives::{
+    AddMissionObjectiveEntity, EditMissionObjectiveEntity, MissionObjectiveEntity,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait MissionObjectiveRepository {
+    async fn gets(&self, mission_id: i32) -> Result<Vec<MissionObjectiveEntity>>;
+    async fn find_by_id(&self, objective_id: i32) -> Result<MissionObjectiveEntity>;
+    async fn next_position(&self, mission_id: i32) -> Result<i32>;
+    async fn add(&self, add_objective_entity: AddMissionObjectiveEntity) -> Result<i32>;
+    async fn edit(
+        &self,
+        objective_id: i32,
+        edit_objective_entity: EditMissionObjectiveEntity,
+    ) -> Result<i32>;
+    async fn remove(&self, objective_id: i32) -> Result<()>;
+    async fn set_completed(&self, objective_id: i32, completed_by: Option<i32>) -> Result<i32>;
+}
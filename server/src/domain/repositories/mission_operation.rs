@@ -9,6 +9,7 @@ pub trait MissionOperationRepository {
         chief_id: i32,
         deadline: Option<chrono::NaiveDateTime>,
     ) -> Result<i32>;
-    async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
+    /// Marks the mission Completed and awards `xp` to everyone on it in the same transaction.
+    async fn to_completed(&self, mission_id: i32, chief_id: i32, xp: i32) -> Result<i32>;
     async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
 }
@@ -9,5 +9,7 @@ pub trait MissionViewingRepository {
     async fn view_detail(&self, mission_id: i32) -> Result<MissionModel>;
     async fn gets(&self, filter: &MissionFilter) -> Result<Vec<MissionModel>>;
     async fn crew_counting(&self, mission_id: i32) -> Result<u32>;
+    async fn is_crew_member(&self, mission_id: i32, brawler_id: i32) -> Result<bool>;
     async fn get_mission_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>>;
+    async fn gets_deleted(&self, chief_id: i32) -> Result<Vec<MissionModel>>;
 }
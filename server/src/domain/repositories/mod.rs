@@ -1,7 +1,22 @@
+pub mod audit_log;
+pub mod brawler_identities;
+pub mod brawler_ratings;
 pub mod brawlers;
 pub mod crew_operation;
 pub mod dashboard;
+pub mod identity_provider;
+pub mod image_processor;
+pub mod image_storage;
+pub mod leaderboard;
+pub mod login_attempts;
+pub mod mailer;
+pub mod mission_attachments;
+pub mod mission_comments;
 pub mod mission_management;
+pub mod mission_objectives;
 pub mod mission_operation;
 pub mod mission_viewing;
+pub mod password_reset;
+pub mod personal_access_tokens;
+pub mod progression;
 pub mod transaction_provider;
@@ -0,0 +1,19 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+#[async_trait]
+pub trait PasswordResetRepository {
+    /// Stores a new token for the brawler, revoking any token issued before it.
+    async fn issue(
+        &self,
+        brawler_id: i32,
+        token_hash: String,
+        expires_at: NaiveDateTime,
+    ) -> Result<()>;
+    /// The brawler a still-usable token belongs to, if any.
+    async fn find_brawler_id(&self, token_hash: String) -> Result<Option<i32>>;
+    /// Marks the token used and sets the new password in one go. Returns `false` when the
+    /// token is unknown, expired or already used.
+    async fn consume(&self, token_hash: String, hashed_password: String) -> Result<bool>;
+}
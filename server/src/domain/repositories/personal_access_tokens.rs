@@ -0,0 +1,24 @@
+use crate::domain::entities::personal_access_tokens::{
+    AddPersonalAccessTokenEntity, PersonalAccessTokenEntity,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait PersonalAccessTokenRepository {
+    async fn create(
+        &self,
+        add_personal_access_token_entity: AddPersonalAccessTokenEntity,
+    ) -> Result<PersonalAccessTokenEntity>;
+    /// The brawler's tokens that haven't been revoked, newest first.
+    async fn list(&self, brawler_id: i32) -> Result<Vec<PersonalAccessTokenEntity>>;
+    /// Returns `false` when the brawler has no such token, or it is already revoked.
+    async fn revoke(&self, brawler_id: i32, token_id: i32) -> Result<bool>;
+    /// A token that is neither revoked nor expired.
+    async fn find_active_by_prefix(
+        &self,
+        token_prefix: String,
+    ) -> Result<Option<PersonalAccessTokenEntity>>;
+    /// Records that the token was just used.
+    async fn touch(&self, token_id: i32) -> Result<()>;
+}
@@ -0,0 +1,17 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::value_objects::{brawler_stats::BrawlerStats, progression::Achievement};
+
+#[async_trait]
+pub trait ProgressionRepository {
+    /// The chief and every crew member of the mission.
+    async fn mission_participants(&self, mission_id: i32) -> Result<Vec<i32>>;
+    async fn get_stats(&self, brawler_id: i32) -> Result<BrawlerStats>;
+    /// Stores the achievements and returns the ones that were not unlocked before.
+    async fn unlock_achievements(
+        &self,
+        brawler_id: i32,
+        achievements: Vec<Achievement>,
+    ) -> Result<Vec<Achievement>>;
+}
@@ -0,0 +1,180 @@
+use crate::domain::{
+    entities::audit_log::AuditLogEntity, value_objects::mission_model::MissionModel,
+};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum AuditAction {
+    MissionCreated,
+    MissionEdited,
+    MissionDeleted,
+    MissionRestored,
+    MissionStarted,
+    MissionCompleted,
+    MissionFailed,
+    CrewJoined,
+    CrewLeft,
+    CrewKicked,
+}
+
+impl Display for AuditAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditAction::MissionCreated => write!(f, "mission.created"),
+            AuditAction::MissionEdited => write!(f, "mission.edited"),
+            AuditAction::MissionDeleted => write!(f, "mission.deleted"),
+            AuditAction::MissionRestored => write!(f, "mission.restored"),
+            AuditAction::MissionStarted => write!(f, "mission.started"),
+            AuditAction::MissionCompleted => write!(f, "mission.completed"),
+            AuditAction::MissionFailed => write!(f, "mission.failed"),
+            AuditAction::CrewJoined => write!(f, "crew.joined"),
+            AuditAction::CrewLeft => write!(f, "crew.left"),
+            AuditAction::CrewKicked => write!(f, "crew.kicked"),
+        }
+    }
+}
+
+/// What an action was done to; crew actions target the member, on a mission.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AuditTarget {
+    Mission(i32),
+    CrewMember { mission_id: i32, brawler_id: i32 },
+}
+
+impl AuditTarget {
+    pub fn target_type(&self) -> &'static str {
+        match self {
+            AuditTarget::Mission(_) => "mission",
+            AuditTarget::CrewMember { .. } => "brawler",
+        }
+    }
+
+    pub fn target_id(&self) -> i32 {
+        match self {
+            AuditTarget::Mission(mission_id) => *mission_id,
+            AuditTarget::CrewMember { brawler_id, .. } => *brawler_id,
+        }
+    }
+
+    pub fn mission_id(&self) -> i32 {
+        match self {
+            AuditTarget::Mission(mission_id) => *mission_id,
+            AuditTarget::CrewMember { mission_id, .. } => *mission_id,
+        }
+    }
+}
+
+/// Where a mutating request came from, taken from the request by the handler.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditContext {
+    pub request_id: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+/// The fields of a mission an audit entry tracks; derived values such as `crew_count`,
+/// `progress` and `updated_at` would only add noise to the diff.
+pub fn mission_snapshot(mission: &MissionModel) -> Value {
+    json!({
+        "name": mission.name,
+        "description": mission.description,
+        "status": mission.status,
+        "max_crew": mission.max_crew,
+        "deadline": mission.deadline,
+        "duration": mission.duration,
+        "require_objectives": mission.require_objectives,
+    })
+}
+
+/// Keeps only the top-level fields that differ between two snapshots.
+pub fn diff(before: Value, after: Value) -> (Option<Value>, Option<Value>) {
+    match (before, after) {
+        (Value::Object(before), Value::Object(mut after)) => {
+            let mut changed_before = Map::new();
+            for (key, value) in before {
+                match after.get(&key) {
+                    Some(new_value) if *new_value == value => {
+                        after.remove(&key);
+                    }
+                    _ => {
+                        changed_before.insert(key, value);
+                    }
+                }
+            }
+            (
+                Some(Value::Object(changed_before)),
+                Some(Value::Object(after)),
+            )
+        }
+        (before, after) => (Some(before), Some(after)),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AuditLogQuery {
+    pub actor_id: Option<i32>,
+    pub mission_id: Option<i32>,
+    /// Inclusive lower bound on `created_at`, e.g. `2026-10-19T00:00:00`.
+    pub from: Option<NaiveDateTime>,
+    /// Exclusive upper bound on `created_at`.
+    pub to: Option<NaiveDateTime>,
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+}
+
+impl AuditLogQuery {
+    pub fn page(&self) -> i64 {
+        self.page.unwrap_or(1).max(1)
+    }
+
+    pub fn per_page(&self) -> i64 {
+        self.per_page.unwrap_or(50).clamp(1, 200)
+    }
+
+    pub fn offset(&self) -> i64 {
+        (self.page() - 1) * self.per_page()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AuditLogModel {
+    pub id: i64,
+    pub actor_id: i32,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: i32,
+    pub mission_id: Option<i32>,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub request_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<AuditLogEntity> for AuditLogModel {
+    fn from(entity: AuditLogEntity) -> Self {
+        Self {
+            id: entity.id,
+            actor_id: entity.actor_id,
+            action: entity.action,
+            target_type: entity.target_type,
+            target_id: entity.target_id,
+            mission_id: entity.mission_id,
+            before: entity.before,
+            after: entity.after,
+            request_id: entity.request_id,
+            ip_address: entity.ip_address,
+            created_at: entity.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLogPage {
+    pub items: Vec<AuditLogModel>,
+    pub page: i64,
+    pub per_page: i64,
+    pub total: i64,
+}
@@ -1,6 +1,18 @@
 use anyhow::Result;
 use base64::{Engine, engine::general_purpose};
 
+const SUPPORTED_MIME_TYPES: [&str; 4] = ["image/png", "image/jpeg", "image/webp", "image/gif"];
+
+pub const MAX_IMAGE_BYTES: usize = 5 * 1024 * 1024;
+
+/// Sniffs the image type from its leading bytes, rejecting anything we do not accept.
+pub fn sniff_mime_type(bytes: &[u8]) -> Result<&'static str> {
+    match infer::get(bytes) {
+        Some(t) if SUPPORTED_MIME_TYPES.contains(&t.mime_type()) => Ok(t.mime_type()),
+        _ => Err(anyhow::anyhow!("un-support file type.")),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Base64Image(String);
 
@@ -14,21 +26,51 @@ impl Base64Image {
             .decode(&data)
             .map_err(|_| anyhow::anyhow!("Invalid image data."))?;
 
-        let file_type = match infer::get(&bytes) {
-            Some(t) if t.mime_type() == "image/png" || t.mime_type() == "image/jpeg" => {
-                t.mime_type()
-            }
-            _ => {
-                return Err(anyhow::anyhow!(
-                    "un-support file type."
-                ));
-            }
-        };
-
-        
+        if bytes.len() > MAX_IMAGE_BYTES {
+            return Err(anyhow::anyhow!(
+                "Image must not exceed {} bytes",
+                MAX_IMAGE_BYTES
+            ));
+        }
+
+        let file_type = sniff_mime_type(&bytes)?;
+
         Ok(Self(format!("data:{};base64,{}", file_type, data)))
     }
 
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.is_empty() {
+            return Err(anyhow::anyhow!(" data cannot be empty!!"));
+        }
+
+        if bytes.len() > MAX_IMAGE_BYTES {
+            return Err(anyhow::anyhow!(
+                "Image must not exceed {} bytes",
+                MAX_IMAGE_BYTES
+            ));
+        }
+
+        let file_type = sniff_mime_type(bytes)?;
+
+        Ok(Self(format!(
+            "data:{};base64,{}",
+            file_type,
+            general_purpose::STANDARD.encode(bytes)
+        )))
+    }
+
+    pub fn decode(&self) -> Result<Vec<u8>> {
+        let data = self
+            .0
+            .split_once(',')
+            .map(|(_, data)| data)
+            .ok_or_else(|| anyhow::anyhow!("Invalid image data."))?;
+
+        general_purpose::STANDARD
+            .decode(data)
+            .map_err(|_| anyhow::anyhow!("Invalid image data."))
+    }
+
     pub fn into_inner(self) -> String {
         self.0
     }
@@ -1,13 +1,56 @@
-use crate::domain::entities::brawlers::RegisterBrawlerEntity;
+use crate::domain::{
+    entities::brawlers::RegisterBrawlerEntity,
+    value_objects::{
+        brawler_rating_model::{RatingSummary, Reputation},
+        brawler_stats::BrawlerStats,
+        credentials,
+        mission_model::MissionModel,
+        progression::{Level, UnlockedAchievement},
+        validation::{Validate, ValidationErrors},
+    },
+};
+use chrono::NaiveDateTime;
 use diesel::prelude::QueryableByName;
-use diesel::sql_types::{BigInt, Varchar};
+use diesel::sql_types::{BigInt, Int4, Nullable, Timestamp, Varchar};
 use serde::{Deserialize, Serialize};
 
+pub const MAX_DISPLAY_NAME_LENGTH: usize = 50;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegisterBrawlerModel {
     pub username: String,
     pub password: String,
     pub display_name: String,
+    #[serde(default)]
+    pub email: Option<String>,
+}
+
+/// Checked by `BrawlersUseCase::register`, next to the same password rules that password
+/// change and reset apply, rather than by the extractor.
+impl Validate for RegisterBrawlerModel {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        errors
+            .check("username", credentials::validate_username(&self.username))
+            .check(
+                "password",
+                credentials::validate_password(&self.password, &self.username),
+            )
+            .length(
+                "display_name",
+                &self.display_name,
+                1,
+                MAX_DISPLAY_NAME_LENGTH,
+            );
+        if let Some(email) = self
+            .email
+            .as_deref()
+            .filter(|email| !email.trim().is_empty())
+        {
+            errors.check("email", credentials::validate_email(email));
+        }
+        errors.into_result()
+    }
 }
 
 impl RegisterBrawlerModel {
@@ -15,7 +58,12 @@ impl RegisterBrawlerModel {
         RegisterBrawlerEntity {
             username: self.username.clone(),
             password: self.password.clone(),
-            display_name: self.display_name.clone(),
+            display_name: self.display_name.trim().to_string(),
+            email: self
+                .email
+                .as_ref()
+                .map(|email| email.trim().to_lowercase())
+                .filter(|email| !email.is_empty()),
         }
     }
 }
@@ -30,9 +78,79 @@ pub struct BrawlerModel {
     pub mission_success_count: i64,
     #[diesel(sql_type = BigInt)]
     pub mission_joined_count: i64,
+    #[diesel(embed)]
+    #[serde(flatten)]
+    pub stats: BrawlerStats,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateBrawlerModel {
     pub display_name: String,
 }
+
+impl Validate for UpdateBrawlerModel {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        errors.length(
+            "display_name",
+            &self.display_name,
+            1,
+            MAX_DISPLAY_NAME_LENGTH,
+        );
+        errors.into_result()
+    }
+}
+
+#[derive(Debug, Clone, QueryableByName)]
+pub struct BrawlerProfileStats {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub display_name: String,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub avatar_url: Option<String>,
+    #[diesel(sql_type = Timestamp)]
+    pub joined_at: NaiveDateTime,
+    #[diesel(sql_type = BigInt)]
+    pub xp: i64,
+    #[diesel(embed)]
+    pub stats: BrawlerStats,
+    #[diesel(embed)]
+    pub ratings: RatingSummary,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BrawlerProfile {
+    pub id: i32,
+    pub display_name: String,
+    pub avatar_url: Option<String>,
+    pub joined_at: NaiveDateTime,
+    pub level: Level,
+    #[serde(flatten)]
+    pub stats: BrawlerStats,
+    pub success_rate: Option<f64>,
+    pub reputation: Reputation,
+    pub recent_missions: Vec<MissionModel>,
+    pub achievements: Vec<UnlockedAchievement>,
+}
+
+impl BrawlerProfile {
+    pub fn new(
+        profile_stats: BrawlerProfileStats,
+        recent_missions: Vec<MissionModel>,
+        achievements: Vec<UnlockedAchievement>,
+    ) -> Self {
+        Self {
+            id: profile_stats.id,
+            display_name: profile_stats.display_name,
+            avatar_url: profile_stats.avatar_url,
+            joined_at: profile_stats.joined_at,
+            level: Level::from_xp(profile_stats.xp),
+            success_rate: profile_stats.stats.success_rate(),
+            stats: profile_stats.stats,
+            reputation: Reputation::from(profile_stats.ratings),
+            recent_missions,
+            achievements,
+        }
+    }
+}
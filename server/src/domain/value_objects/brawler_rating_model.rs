@@ -0,0 +1,70 @@
+use crate::domain::entities::brawler_ratings::AddBrawlerRatingEntity;
+use diesel::{QueryableByName, sql_types::BigInt};
+use serde::{Deserialize, Serialize};
+
+pub const MIN_RATING_SCORE: i32 = 1;
+pub const MAX_RATING_SCORE: i32 = 5;
+pub const MAX_RATING_COMMENT_LENGTH: usize = 500;
+
+/// Neutral score new brawlers start from, and how many ratings it weighs as.
+const PRIOR_SCORE: f64 = 3.0;
+const PRIOR_WEIGHT: f64 = 5.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddBrawlerRatingModel {
+    pub score: i32,
+    pub comment: Option<String>,
+}
+
+impl AddBrawlerRatingModel {
+    pub fn to_entity(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+        ratee_id: i32,
+    ) -> AddBrawlerRatingEntity {
+        AddBrawlerRatingEntity {
+            mission_id,
+            rater_id,
+            ratee_id,
+            score: self.score,
+            comment: self
+                .comment
+                .as_ref()
+                .map(|comment| comment.trim().to_string())
+                .filter(|comment| !comment.is_empty()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, QueryableByName)]
+pub struct RatingSummary {
+    #[diesel(sql_type = BigInt)]
+    pub ratings_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub ratings_sum: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Reputation {
+    /// Bayesian average pulled towards a neutral 3.0, so a couple of ratings can't max it out.
+    pub score: f64,
+    pub average: Option<f64>,
+    pub ratings_count: i64,
+}
+
+impl From<RatingSummary> for Reputation {
+    fn from(summary: RatingSummary) -> Self {
+        let count = summary.ratings_count as f64;
+        let sum = summary.ratings_sum as f64;
+
+        let score = (sum + PRIOR_SCORE * PRIOR_WEIGHT) / (count + PRIOR_WEIGHT);
+        let average = (summary.ratings_count > 0).then(|| (sum / count * 100.0).round() / 100.0);
+
+        Self {
+            score: (score * 100.0).round() / 100.0,
+            average,
+            ratings_count: summary.ratings_count,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use diesel::{QueryableByName, sql_types::BigInt};
+use serde::{Deserialize, Serialize};
+
+/// Per-brawler mission counters, read from the `brawler_stats` view so that every
+/// endpoint reporting them agrees on what counts as completed, failed or abandoned.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, QueryableByName)]
+pub struct BrawlerStats {
+    #[diesel(sql_type = BigInt)]
+    pub missions_led: i64,
+    #[diesel(sql_type = BigInt)]
+    pub missions_joined: i64,
+    #[diesel(sql_type = BigInt)]
+    pub completed_as_chief: i64,
+    #[diesel(sql_type = BigInt)]
+    pub failed_as_chief: i64,
+    #[diesel(sql_type = BigInt)]
+    pub completed_as_crew: i64,
+    #[diesel(sql_type = BigInt)]
+    pub failed_as_crew: i64,
+    #[diesel(sql_type = BigInt)]
+    pub abandoned: i64,
+}
+
+impl BrawlerStats {
+    pub fn completed(&self) -> i64 {
+        self.completed_as_chief + self.completed_as_crew
+    }
+
+    pub fn failed(&self) -> i64 {
+        self.failed_as_chief + self.failed_as_crew
+    }
+
+    /// Percentage of finished missions that were completed, `None` until one has finished.
+    pub fn success_rate(&self) -> Option<f64> {
+        let finished = self.completed() + self.failed();
+        if finished == 0 {
+            return None;
+        }
+
+        Some((self.completed() as f64 * 1000.0 / finished as f64).round() / 10.0)
+    }
+}
@@ -0,0 +1,116 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+pub const MIN_USERNAME_LENGTH: usize = 3;
+pub const MAX_USERNAME_LENGTH: usize = 32;
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+pub const MAX_PASSWORD_LENGTH: usize = 128;
+pub const MAX_EMAIL_LENGTH: usize = 255;
+
+/// Usernames are 3-32 ASCII letters, digits, `_`, `.` or `-`, starting with a letter or digit.
+pub fn validate_username(username: &str) -> Result<()> {
+    let length = username.chars().count();
+    if !(MIN_USERNAME_LENGTH..=MAX_USERNAME_LENGTH).contains(&length) {
+        return Err(anyhow::anyhow!(
+            "Username must be between {} and {} characters long!",
+            MIN_USERNAME_LENGTH,
+            MAX_USERNAME_LENGTH
+        ));
+    }
+
+    if !username.starts_with(|c: char| c.is_ascii_alphanumeric())
+        || !username
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+    {
+        return Err(anyhow::anyhow!(
+            "Username may only contain letters, digits, '_', '.' or '-' and must start with a letter or digit!"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Passwords need 8-128 characters with a lowercase letter, an uppercase letter and a digit,
+/// and must not contain the username.
+pub fn validate_password(password: &str, username: &str) -> Result<()> {
+    let length = password.chars().count();
+    if !(MIN_PASSWORD_LENGTH..=MAX_PASSWORD_LENGTH).contains(&length) {
+        return Err(anyhow::anyhow!(
+            "Password must be between {} and {} characters long!",
+            MIN_PASSWORD_LENGTH,
+            MAX_PASSWORD_LENGTH
+        ));
+    }
+
+    let has_lowercase = password.chars().any(|c| c.is_lowercase());
+    let has_uppercase = password.chars().any(|c| c.is_uppercase());
+    let has_digit = password.chars().any(|c| c.is_ascii_digit());
+    if !(has_lowercase && has_uppercase && has_digit) {
+        return Err(anyhow::anyhow!(
+            "Password must contain a lowercase letter, an uppercase letter and a digit!"
+        ));
+    }
+
+    if !username.is_empty() && password.to_lowercase().contains(&username.to_lowercase()) {
+        return Err(anyhow::anyhow!("Password must not contain the username!"));
+    }
+
+    Ok(())
+}
+
+pub fn validate_email(email: &str) -> Result<()> {
+    let email = email.trim();
+    let is_valid = email.chars().count() <= MAX_EMAIL_LENGTH
+        && !email.contains(char::is_whitespace)
+        && email.split_once('@').is_some_and(|(local, domain)| {
+            !local.is_empty()
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+        });
+
+    if !is_valid {
+        return Err(anyhow::anyhow!("Email is not a valid address!"));
+    }
+
+    Ok(())
+}
+
+/// Refusals of a password change or reset that are not rule violations.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CredentialsError {
+    WrongCurrentPassword,
+    InvalidResetToken,
+}
+
+impl Display for CredentialsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CredentialsError::WrongCurrentPassword => write!(f, "Invalid password!"),
+            CredentialsError::InvalidResetToken => {
+                write!(f, "Reset token is invalid or has expired!")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CredentialsError {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangePasswordModel {
+    pub current_password: String,
+    pub new_password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasswordResetRequestModel {
+    pub username: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasswordResetConfirmModel {
+    pub token: String,
+    pub new_password: String,
+}
@@ -1,6 +1,8 @@
 use diesel::prelude::*;
 use serde::Serialize;
 
+use crate::domain::value_objects::brawler_stats::BrawlerStats;
+
 #[derive(Debug, QueryableByName, Serialize)]
 pub struct DashboardStats {
     #[diesel(sql_type = diesel::sql_types::BigInt)]
@@ -23,4 +25,7 @@ pub struct UserDashboard {
     pub success_count: i64,
     #[diesel(sql_type = diesel::sql_types::BigInt)]
     pub total_participated: i64,
+    #[diesel(embed)]
+    #[serde(flatten)]
+    pub stats: BrawlerStats,
 }
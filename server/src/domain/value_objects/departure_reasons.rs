@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum DepartureReasons {
+    Left,
+    Kicked,
+}
+
+impl Display for DepartureReasons {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DepartureReasons::Left => write!(f, "Left"),
+            DepartureReasons::Kicked => write!(f, "Kicked"),
+        }
+    }
+}
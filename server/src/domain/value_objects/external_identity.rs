@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+pub const IDENTITY_ALREADY_LINKED: &str = "This identity is already linked to another brawler!";
+pub const IDENTITY_NOT_LINKED: &str = "This identity is not linked to any brawler!";
+pub const INVALID_OIDC_STATE: &str = "Sign-in session is missing, expired or does not match!";
+pub const OIDC_SIGN_IN_REFUSED: &str = "Sign-in was refused by the identity provider!";
+
+/// A user as vouched for by the identity provider's verified ID token.
+#[derive(Debug, Clone)]
+pub struct ExternalIdentity {
+    pub issuer: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub name: Option<String>,
+    pub preferred_username: Option<String>,
+}
+
+/// What the provider needs to bind its callback to the browser that started the flow.
+#[derive(Debug, Clone)]
+pub struct AuthorizationRequest {
+    pub state: String,
+    pub nonce: String,
+    /// Base64url SHA-256 of the PKCE code verifier.
+    pub code_challenge: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcCallbackQuery {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    /// Set by the provider instead of `code` when the user denied or the request was bad.
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OidcAuthorizationModel {
+    pub authorization_url: String,
+}
@@ -0,0 +1,90 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    QueryableByName,
+    sql_types::{BigInt, Double, Int4, Nullable, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_LEADERBOARD_LIMIT: i64 = 10;
+pub const MAX_LEADERBOARD_LIMIT: i64 = 100;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum LeaderboardCategory {
+    Completed,
+    SuccessRate,
+    Led,
+    Activity,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum LeaderboardPeriod {
+    Week,
+    Month,
+    #[default]
+    AllTime,
+}
+
+impl LeaderboardPeriod {
+    /// The `period` key used by the `leaderboard_stats` materialized view.
+    pub fn as_key(&self) -> &'static str {
+        match self {
+            LeaderboardPeriod::Week => "week",
+            LeaderboardPeriod::Month => "month",
+            LeaderboardPeriod::AllTime => "all",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LeaderboardQuery {
+    pub period: Option<LeaderboardPeriod>,
+    pub limit: Option<i64>,
+}
+
+impl LeaderboardQuery {
+    pub fn period(&self) -> LeaderboardPeriod {
+        self.period.unwrap_or_default()
+    }
+
+    pub fn limit(&self) -> i64 {
+        self.limit
+            .unwrap_or(DEFAULT_LEADERBOARD_LIMIT)
+            .clamp(1, MAX_LEADERBOARD_LIMIT)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, QueryableByName)]
+pub struct LeaderboardEntry {
+    #[diesel(sql_type = BigInt)]
+    pub rank: i64,
+    #[diesel(sql_type = Int4)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub display_name: String,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub avatar_url: Option<String>,
+    #[diesel(sql_type = BigInt)]
+    pub completed: i64,
+    #[diesel(sql_type = BigInt)]
+    pub failed: i64,
+    #[diesel(sql_type = BigInt)]
+    pub led: i64,
+    #[diesel(sql_type = BigInt)]
+    pub activity: i64,
+    #[diesel(sql_type = Nullable<Double>)]
+    pub success_rate: Option<f64>,
+    #[diesel(sql_type = Timestamp)]
+    pub refreshed_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Leaderboard {
+    pub category: LeaderboardCategory,
+    pub period: LeaderboardPeriod,
+    /// Finished missions a brawler needs before being ranked by success rate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_sample: Option<i64>,
+    pub entries: Vec<LeaderboardEntry>,
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The only error a failed login reports, whether the username exists or not.
+pub const INVALID_CREDENTIALS: &str = "Invalid username or password!";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum LoginFailureReasons {
+    UnknownUser,
+    WrongPassword,
+    Locked,
+}
+
+impl Display for LoginFailureReasons {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoginFailureReasons::UnknownUser => write!(f, "UnknownUser"),
+            LoginFailureReasons::WrongPassword => write!(f, "WrongPassword"),
+            LoginFailureReasons::Locked => write!(f, "Locked"),
+        }
+    }
+}
+
+/// Returned by a login attempt while its username or client IP is locked out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoginLocked {
+    pub retry_after: i64,
+}
+
+impl Display for LoginLocked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Too many failed login attempts, try again in {} seconds!",
+            self.retry_after
+        )
+    }
+}
+
+impl std::error::Error for LoginLocked {}
+
+pub fn user_key(username: &str) -> String {
+    format!("user:{}", username.trim().to_lowercase())
+}
+
+pub fn ip_key(ip_address: &str) -> String {
+    format!("ip:{}", ip_address)
+}
+
+/// Seconds to lock a key for after `failures` consecutive failures. Nothing happens below
+/// `max_failures`; from there the lockout starts at `base` and doubles per extra failure,
+/// capped at `max`.
+pub fn lockout_seconds(failures: i32, max_failures: i32, base: i64, max: i64) -> Option<i64> {
+    if failures < max_failures {
+        return None;
+    }
+
+    let doublings = (failures - max_failures).min(32) as u32;
+    Some(base.saturating_mul(1_i64 << doublings).min(max))
+}
@@ -0,0 +1,77 @@
+use crate::domain::entities::mission_comments::AddMissionCommentEntity;
+use chrono::NaiveDateTime;
+use diesel::{
+    QueryableByName,
+    sql_types::{Int4, Text, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+pub const MAX_COMMENT_LENGTH: usize = 2000;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct MissionCommentModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Int4)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Int4)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub display_name: String,
+    #[diesel(sql_type = Varchar)]
+    pub avatar_url: String,
+    #[diesel(sql_type = Text)]
+    pub content: String,
+    #[diesel(sql_type = Timestamp)]
+    pub created_at: NaiveDateTime,
+    #[diesel(sql_type = Timestamp)]
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddMissionCommentModel {
+    pub content: String,
+}
+
+impl AddMissionCommentModel {
+    pub fn to_entity(&self, mission_id: i32, brawler_id: i32) -> AddMissionCommentEntity {
+        AddMissionCommentEntity {
+            mission_id,
+            brawler_id,
+            content: self.content.trim().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditMissionCommentModel {
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CommentQuery {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+}
+
+impl CommentQuery {
+    pub fn page(&self) -> i64 {
+        self.page.unwrap_or(1).max(1)
+    }
+
+    pub fn per_page(&self) -> i64 {
+        self.per_page.unwrap_or(20).clamp(1, 100)
+    }
+
+    pub fn offset(&self) -> i64 {
+        (self.page() - 1) * self.per_page()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissionCommentPage {
+    pub items: Vec<MissionCommentModel>,
+    pub page: i64,
+    pub per_page: i64,
+    pub total: i64,
+}
@@ -1,11 +1,14 @@
 use crate::domain::{
     entities::missions::{AddMissionEntity, EditMissionEntity},
-    value_objects::mission_statuses::MissionStatuses,
+    value_objects::{
+        mission_statuses::MissionStatuses,
+        validation::{Validate, ValidationErrors},
+    },
 };
 use chrono::NaiveDateTime;
 use diesel::{
     QueryableByName,
-    sql_types::{BigInt, Int4, Nullable, Text, Timestamp, Varchar},
+    sql_types::{BigInt, Bool, Int4, Nullable, Text, Timestamp, Varchar},
 };
 use serde::{Deserialize, Serialize};
 
@@ -31,12 +34,51 @@ pub struct MissionModel {
     pub deadline: Option<NaiveDateTime>,
     #[diesel(sql_type = Nullable<Int4>)]
     pub duration: Option<i32>,
+    #[diesel(sql_type = Bool)]
+    pub require_objectives: bool,
+    /// Percentage of completed objectives, `None` when the mission has no objectives.
+    #[diesel(sql_type = Nullable<Int4>)]
+    pub progress: Option<i32>,
     #[diesel(sql_type = Timestamp)]
     pub created_at: NaiveDateTime,
     #[diesel(sql_type = Timestamp)]
     pub updated_at: NaiveDateTime,
 }
 
+pub const MIN_MISSION_NAME_LENGTH: usize = 3;
+pub const MAX_MISSION_NAME_LENGTH: usize = 255;
+pub const MAX_MISSION_DESCRIPTION_LENGTH: usize = 5000;
+pub const MAX_CREW_LIMIT: i64 = 100;
+/// Longest mission duration in minutes (30 days).
+pub const MAX_MISSION_DURATION: i64 = 60 * 24 * 30;
+
+/// Rules shared by adding and editing; only the fields that are present are checked.
+fn validate_mission_fields(
+    errors: &mut ValidationErrors,
+    description: Option<&String>,
+    max_crew: Option<i32>,
+    deadline: Option<NaiveDateTime>,
+    duration: Option<i32>,
+) {
+    if let Some(description) = description {
+        errors.length(
+            "description",
+            description,
+            0,
+            MAX_MISSION_DESCRIPTION_LENGTH,
+        );
+    }
+    if let Some(max_crew) = max_crew {
+        errors.range("max_crew", max_crew as i64, 1, MAX_CREW_LIMIT);
+    }
+    if let Some(deadline) = deadline {
+        errors.not_in_past("deadline", deadline);
+    }
+    if let Some(duration) = duration {
+        errors.range("duration", duration as i64, 1, MAX_MISSION_DURATION);
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddMissionModel {
     pub name: String,
@@ -44,18 +86,40 @@ pub struct AddMissionModel {
     pub max_crew: Option<i32>,
     pub deadline: Option<NaiveDateTime>,
     pub duration: Option<i32>,
+    pub require_objectives: Option<bool>,
+}
+
+impl Validate for AddMissionModel {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        errors.length(
+            "name",
+            &self.name,
+            MIN_MISSION_NAME_LENGTH,
+            MAX_MISSION_NAME_LENGTH,
+        );
+        validate_mission_fields(
+            &mut errors,
+            self.description.as_ref(),
+            self.max_crew,
+            self.deadline,
+            self.duration,
+        );
+        errors.into_result()
+    }
 }
 
 impl AddMissionModel {
     pub fn to_entity(&self, chief_id: i32) -> AddMissionEntity {
         AddMissionEntity {
-            name: self.name.clone(),
+            name: self.name.trim().to_string(),
             description: self.description.clone(),
             status: MissionStatuses::Open.to_string(),
             chief_id,
             max_crew: self.max_crew.unwrap_or(3),
             deadline: self.deadline,
             duration: self.duration,
+            require_objectives: self.require_objectives.unwrap_or(false),
         }
     }
 }
@@ -67,6 +131,30 @@ pub struct EditMissionModel {
     pub max_crew: Option<i32>,
     pub deadline: Option<NaiveDateTime>,
     pub duration: Option<i32>,
+    pub require_objectives: Option<bool>,
+}
+
+impl Validate for EditMissionModel {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        // A blank name means "keep the current one".
+        if let Some(name) = self.name.as_ref().filter(|name| !name.trim().is_empty()) {
+            errors.length(
+                "name",
+                name,
+                MIN_MISSION_NAME_LENGTH,
+                MAX_MISSION_NAME_LENGTH,
+            );
+        }
+        validate_mission_fields(
+            &mut errors,
+            self.description.as_ref(),
+            self.max_crew,
+            self.deadline,
+            self.duration,
+        );
+        errors.into_result()
+    }
 }
 
 impl EditMissionModel {
@@ -79,6 +167,7 @@ impl EditMissionModel {
             max_crew: self.max_crew,
             deadline: self.deadline,
             duration: self.duration,
+            require_objectives: self.require_objectives,
         }
     }
 }
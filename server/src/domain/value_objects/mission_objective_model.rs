@@ -0,0 +1,37 @@
+use crate::domain::entities::mission_objectives::{
+    AddMissionObjectiveEntity, EditMissionObjectiveEntity,
+};
+use serde::{Deserialize, Serialize};
+
+pub const MAX_OBJECTIVE_TITLE_LENGTH: usize = 255;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddMissionObjectiveModel {
+    pub title: String,
+    pub position: Option<i32>,
+}
+
+impl AddMissionObjectiveModel {
+    pub fn to_entity(&self, mission_id: i32, position: i32) -> AddMissionObjectiveEntity {
+        AddMissionObjectiveEntity {
+            mission_id,
+            position,
+            title: self.title.trim().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditMissionObjectiveModel {
+    pub title: Option<String>,
+    pub position: Option<i32>,
+}
+
+impl EditMissionObjectiveModel {
+    pub fn to_entity(&self) -> EditMissionObjectiveEntity {
+        EditMissionObjectiveEntity {
+            position: self.position,
+            title: self.title.as_ref().map(|title| title.trim().to_string()),
+        }
+    }
+}
@@ -1,7 +1,21 @@
+pub mod audit_log;
 pub mod base64_image;
 pub mod brawler_model;
+pub mod brawler_rating_model;
+pub mod brawler_stats;
+pub mod credentials;
 pub mod dashboard_stats;
+pub mod departure_reasons;
+pub mod external_identity;
+pub mod leaderboard;
+pub mod login_throttle;
+pub mod mail_message;
+pub mod mission_comment_model;
 pub mod mission_filter;
 pub mod mission_model;
+pub mod mission_objective_model;
 pub mod mission_statuses;
+pub mod personal_access_tokens;
+pub mod progression;
 pub mod uploaded_image;
+pub mod validation;
@@ -0,0 +1,130 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
+
+use crate::domain::{
+    entities::personal_access_tokens::PersonalAccessTokenEntity,
+    value_objects::validation::{Validate, ValidationErrors},
+};
+
+/// Every token starts with this, so the middleware can tell it from a JWT and secret scanners
+/// can spot a leaked one.
+pub const PERSONAL_ACCESS_TOKEN_PREFIX: &str = "lolpat_";
+pub const MAX_TOKEN_NAME_LENGTH: usize = 100;
+pub const MAX_TOKEN_TTL_DAYS: i64 = 365;
+
+pub const TOKEN_NAME_TAKEN: &str = "You already have a token with this name!";
+pub const TOKEN_NOT_FOUND: &str = "Personal access token not found!";
+
+/// What a personal access token may do. Reads are a scope of their own so a token for a
+/// dashboard script can't change anything.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum TokenScope {
+    #[serde(rename = "read")]
+    Read,
+    /// Create, edit, run and comment on missions, and rate brawlers.
+    #[serde(rename = "missions:write")]
+    MissionsWrite,
+    /// Join, leave and kick.
+    #[serde(rename = "crew:write")]
+    CrewWrite,
+    /// Display name and avatar; never the password.
+    #[serde(rename = "profile:write")]
+    ProfileWrite,
+}
+
+impl Display for TokenScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenScope::Read => write!(f, "read"),
+            TokenScope::MissionsWrite => write!(f, "missions:write"),
+            TokenScope::CrewWrite => write!(f, "crew:write"),
+            TokenScope::ProfileWrite => write!(f, "profile:write"),
+        }
+    }
+}
+
+impl FromStr for TokenScope {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "read" => Ok(Self::Read),
+            "missions:write" => Ok(Self::MissionsWrite),
+            "crew:write" => Ok(Self::CrewWrite),
+            "profile:write" => Ok(Self::ProfileWrite),
+            _ => Err(anyhow::anyhow!("Unknown token scope {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatePersonalAccessTokenModel {
+    pub name: String,
+    pub scopes: Vec<TokenScope>,
+    /// Never expires when left out.
+    pub expires_in_days: Option<i64>,
+}
+
+impl Validate for CreatePersonalAccessTokenModel {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        errors.length("name", &self.name, 1, MAX_TOKEN_NAME_LENGTH);
+        if self.scopes.is_empty() {
+            errors.add("scopes", "required", "scopes is required");
+        }
+        if let Some(expires_in_days) = self.expires_in_days {
+            errors.range("expires_in_days", expires_in_days, 1, MAX_TOKEN_TTL_DAYS);
+        }
+        errors.into_result()
+    }
+}
+
+/// A token as listed to its owner; the secret itself is never stored, only `token_hint`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonalAccessTokenModel {
+    pub id: i32,
+    pub name: String,
+    pub token_hint: String,
+    pub scopes: Vec<TokenScope>,
+    pub created_at: NaiveDateTime,
+    pub expires_at: Option<NaiveDateTime>,
+    pub last_used_at: Option<NaiveDateTime>,
+}
+
+impl From<PersonalAccessTokenEntity> for PersonalAccessTokenModel {
+    fn from(entity: PersonalAccessTokenEntity) -> Self {
+        Self {
+            id: entity.id,
+            name: entity.name,
+            token_hint: format!("{}{}", PERSONAL_ACCESS_TOKEN_PREFIX, entity.token_prefix),
+            scopes: parse_scopes(&entity.scopes),
+            created_at: entity.created_at,
+            expires_at: entity.expires_at,
+            last_used_at: entity.last_used_at,
+        }
+    }
+}
+
+/// Returned once, right after creation; `token` can't be shown again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedPersonalAccessTokenModel {
+    #[serde(flatten)]
+    pub details: PersonalAccessTokenModel,
+    pub token: String,
+}
+
+/// Who a valid token acts for and what it may do.
+#[derive(Debug, Clone)]
+pub struct PersonalAccessTokenGrant {
+    pub brawler_id: i32,
+    pub scopes: Vec<TokenScope>,
+}
+
+/// Scopes no longer known to the server are dropped rather than failing the whole token.
+pub fn parse_scopes(scopes: &[String]) -> Vec<TokenScope> {
+    scopes
+        .iter()
+        .filter_map(|scope| scope.parse().ok())
+        .collect()
+}
@@ -0,0 +1,162 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::brawler_stats::BrawlerStats;
+
+const BASE_MISSION_XP: i32 = 100;
+const XP_PER_CREW_MEMBER: i32 = 20;
+const XP_PER_DURATION_HOUR: i32 = 5;
+const MAX_DURATION_HOURS: i32 = 72;
+const XP_PER_LEVEL_STEP: i64 = 50;
+
+/// XP every participant earns for a completed mission: a flat base, plus a bonus per
+/// crew member and per hour of planned duration (capped so long missions don't dominate).
+pub fn mission_xp(crew_size: u32, duration_minutes: Option<i32>) -> i32 {
+    let duration_hours = duration_minutes.unwrap_or(0).max(0) / 60;
+
+    BASE_MISSION_XP
+        + XP_PER_CREW_MEMBER * crew_size as i32
+        + XP_PER_DURATION_HOUR * duration_hours.min(MAX_DURATION_HOURS)
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Level {
+    pub level: i32,
+    pub xp: i64,
+    pub next_level_xp: i64,
+}
+
+impl Level {
+    /// Total XP needed to reach `level`; each level costs 100 XP more than the previous.
+    pub fn threshold(level: i32) -> i64 {
+        let level = level as i64;
+        XP_PER_LEVEL_STEP * level * (level - 1)
+    }
+
+    pub fn from_xp(xp: i64) -> Self {
+        let mut level = 1;
+        while Self::threshold(level + 1) <= xp {
+            level += 1;
+        }
+
+        Self {
+            level,
+            xp,
+            next_level_xp: Self::threshold(level + 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Achievement {
+    FirstMissionLed,
+    FirstCompletion,
+    TenCompletions,
+    FiveMissionsLed,
+    NeverAbandoned,
+    Flawless,
+}
+
+pub struct AchievementRule {
+    pub achievement: Achievement,
+    pub title: &'static str,
+    pub description: &'static str,
+    pub unlocked: fn(&BrawlerStats) -> bool,
+}
+
+pub const ACHIEVEMENT_RULES: &[AchievementRule] = &[
+    AchievementRule {
+        achievement: Achievement::FirstMissionLed,
+        title: "First Command",
+        description: "Lead a mission to completion",
+        unlocked: |stats| stats.completed_as_chief >= 1,
+    },
+    AchievementRule {
+        achievement: Achievement::FirstCompletion,
+        title: "First Victory",
+        description: "Complete a mission",
+        unlocked: |stats| stats.completed() >= 1,
+    },
+    AchievementRule {
+        achievement: Achievement::TenCompletions,
+        title: "Veteran",
+        description: "Complete 10 missions",
+        unlocked: |stats| stats.completed() >= 10,
+    },
+    AchievementRule {
+        achievement: Achievement::FiveMissionsLed,
+        title: "Commander",
+        description: "Lead 5 missions",
+        unlocked: |stats| stats.missions_led >= 5,
+    },
+    AchievementRule {
+        achievement: Achievement::NeverAbandoned,
+        title: "Steadfast",
+        description: "Complete 5 missions without ever leaving or being kicked from a crew",
+        unlocked: |stats| stats.completed() >= 5 && stats.abandoned == 0,
+    },
+    AchievementRule {
+        achievement: Achievement::Flawless,
+        title: "Flawless",
+        description: "Complete 5 missions without a single failure",
+        unlocked: |stats| stats.completed() >= 5 && stats.failed() == 0,
+    },
+];
+
+impl Achievement {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Achievement::FirstMissionLed => "first_mission_led",
+            Achievement::FirstCompletion => "first_completion",
+            Achievement::TenCompletions => "ten_completions",
+            Achievement::FiveMissionsLed => "five_missions_led",
+            Achievement::NeverAbandoned => "never_abandoned",
+            Achievement::Flawless => "flawless",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        ACHIEVEMENT_RULES
+            .iter()
+            .map(|rule| rule.achievement)
+            .find(|achievement| achievement.code() == code)
+    }
+
+    pub fn rule(&self) -> &'static AchievementRule {
+        ACHIEVEMENT_RULES
+            .iter()
+            .find(|rule| rule.achievement == *self)
+            .expect("every achievement has a rule")
+    }
+
+    /// Achievements whose rule currently holds for `stats`.
+    pub fn evaluate(stats: &BrawlerStats) -> Vec<Achievement> {
+        ACHIEVEMENT_RULES
+            .iter()
+            .filter(|rule| (rule.unlocked)(stats))
+            .map(|rule| rule.achievement)
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UnlockedAchievement {
+    pub achievement: Achievement,
+    pub title: &'static str,
+    pub description: &'static str,
+    pub unlocked_at: NaiveDateTime,
+}
+
+impl UnlockedAchievement {
+    pub fn new(achievement: Achievement, unlocked_at: NaiveDateTime) -> Self {
+        let rule = achievement.rule();
+
+        Self {
+            achievement,
+            title: rule.title,
+            description: rule.description,
+            unlocked_at,
+        }
+    }
+}
@@ -13,6 +13,23 @@ impl UploadedImage {
     }
 }
 
+/// Square sizes every uploaded avatar is stored in.
+pub const AVATAR_SIZES: [u32; 3] = [64, 128, 256];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvatarVariant {
+    pub size: u32,
+    pub url: String,
+    pub public_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadedAvatar {
+    pub url: String,
+    pub public_id: String,
+    pub variants: Vec<AvatarVariant>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UploadBase64Image {
     pub base64_string: String,
@@ -0,0 +1,105 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FieldError {
+    pub field: String,
+    pub code: &'static str,
+    pub message: String,
+}
+
+/// Collects every rule a model breaks instead of stopping at the first one, so clients can
+/// show all field errors at once.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ValidationErrors {
+    pub errors: Vec<FieldError>,
+}
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, field: &str, code: &'static str, message: impl Into<String>) {
+        self.errors.push(FieldError {
+            field: field.to_string(),
+            code,
+            message: message.into(),
+        });
+    }
+
+    /// Character length of `value` after trimming must be within `min..=max`.
+    pub fn length(&mut self, field: &str, value: &str, min: usize, max: usize) -> &mut Self {
+        let length = value.trim().chars().count();
+        if length < min {
+            if min == 1 {
+                self.add(field, "required", format!("{} is required", field));
+            } else {
+                self.add(
+                    field,
+                    "too_short",
+                    format!("{} must be at least {} characters long", field, min),
+                );
+            }
+        } else if length > max {
+            self.add(
+                field,
+                "too_long",
+                format!("{} must be at most {} characters long", field, max),
+            );
+        }
+        self
+    }
+
+    pub fn range(&mut self, field: &str, value: i64, min: i64, max: i64) -> &mut Self {
+        if !(min..=max).contains(&value) {
+            self.add(
+                field,
+                "out_of_range",
+                format!("{} must be between {} and {}", field, min, max),
+            );
+        }
+        self
+    }
+
+    pub fn not_in_past(&mut self, field: &str, value: NaiveDateTime) -> &mut Self {
+        if value < chrono::Utc::now().naive_utc() {
+            self.add(
+                field,
+                "in_past",
+                format!("{} must not be in the past", field),
+            );
+        }
+        self
+    }
+
+    /// Records the message of a rule implemented as a plain `anyhow::Result` check.
+    pub fn check(&mut self, field: &str, result: anyhow::Result<()>) -> &mut Self {
+        if let Err(e) = result {
+            self.add(field, "invalid", e.to_string());
+        }
+        self
+    }
+
+    pub fn into_result(self) -> Result<(), ValidationErrors> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let messages: Vec<&str> = self.errors.iter().map(|e| e.message.as_str()).collect();
+        write!(f, "Validation failed: {}", messages.join("; "))
+    }
+}
+
+/// Lets use cases return rule violations through `anyhow`; routers downcast them back to `422`.
+impl std::error::Error for ValidationErrors {}
+
+pub trait Validate {
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
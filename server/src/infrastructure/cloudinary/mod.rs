@@ -1,18 +1,16 @@
-use crate::config::{config_loader::get_cloudinary_env, config_model::CloudinaryEnv};
+use crate::config::config_model::CloudinaryEnv;
+use crate::domain::repositories::image_storage::ImageStorage;
 use crate::domain::value_objects::base64_image::Base64Image;
 use crate::domain::value_objects::uploaded_image::{UploadImageOptions, UploadedImage};
 use anyhow::{Context, Result};
+use async_trait::async_trait;
 use chrono::Utc;
 use reqwest::multipart::{Form, Part};
 use sha1::{Digest, Sha1};
 use std::collections::BTreeMap;
 
 fn form_builder(option: UploadImageOptions, cloud_env: &CloudinaryEnv) -> Result<Form> {
-    let mut form = Form::new();
-    let timestamp = Utc::now().timestamp().to_string();
-
     let mut params_to_sign: BTreeMap<String, String> = BTreeMap::new();
-    params_to_sign.insert("timestamp".to_string(), timestamp.clone());
 
     if let Some(folder_name) = option.folder {
         params_to_sign.insert("folder".to_string(), folder_name);
@@ -24,6 +22,18 @@ fn form_builder(option: UploadImageOptions, cloud_env: &CloudinaryEnv) -> Result
         params_to_sign.insert("transformation".to_string(), transformation);
     }
 
+    signed_form(params_to_sign, cloud_env)
+}
+
+fn signed_form(
+    mut params_to_sign: BTreeMap<String, String>,
+    cloud_env: &CloudinaryEnv,
+) -> Result<Form> {
+    let mut form = Form::new();
+    let timestamp = Utc::now().timestamp().to_string();
+
+    params_to_sign.insert("timestamp".to_string(), timestamp);
+
     let mut to_sign = String::new();
     for (i, (key, value)) in params_to_sign.iter().enumerate() {
         if i > 0 {
@@ -44,12 +54,37 @@ fn form_builder(option: UploadImageOptions, cloud_env: &CloudinaryEnv) -> Result
     Ok(form)
 }
 
+pub struct CloudinaryStorage {
+    cloud_env: CloudinaryEnv,
+}
+
+impl CloudinaryStorage {
+    pub fn new(cloud_env: CloudinaryEnv) -> Self {
+        Self { cloud_env }
+    }
+}
+
+#[async_trait]
+impl ImageStorage for CloudinaryStorage {
+    async fn upload(
+        &self,
+        base64_image: Base64Image,
+        option: UploadImageOptions,
+    ) -> Result<UploadedImage> {
+        upload(base64_image, option, &self.cloud_env).await
+    }
+
+    async fn destroy(&self, public_id: String) -> Result<()> {
+        destroy(public_id, &self.cloud_env).await
+    }
+}
+
 pub async fn upload(
     base64_image: Base64Image,
     option: UploadImageOptions,
+    cloud_env: &CloudinaryEnv,
 ) -> Result<UploadedImage> {
-    let cloud_env = get_cloudinary_env()?;
-    let form = form_builder(option, &cloud_env)?;
+    let form = form_builder(option, cloud_env)?;
 
     let file = Part::text(base64_image.into_inner());
     let multipart = form.part("file", file);
@@ -91,3 +126,55 @@ pub async fn upload(
 
     Ok(json)
 }
+
+pub async fn destroy(public_id: String, cloud_env: &CloudinaryEnv) -> Result<()> {
+    let mut params_to_sign: BTreeMap<String, String> = BTreeMap::new();
+    params_to_sign.insert("public_id".to_string(), public_id.clone());
+    params_to_sign.insert("invalidate".to_string(), "true".to_string());
+    let form = signed_form(params_to_sign, cloud_env)?;
+
+    let client = reqwest::Client::new();
+    let url = format!(
+        "https://api.cloudinary.com/v1_1/{}/image/destroy",
+        cloud_env.cloud_name
+    );
+
+    let response = client
+        .post(&url)
+        .multipart(form)
+        .send()
+        .await
+        .context(format!("Failed to send request to {}", url))?;
+
+    let status = response.status();
+    let text = response
+        .text()
+        .await
+        .context("Failed to read response text")?;
+
+    if !status.is_success() {
+        return Err(anyhow::anyhow!(
+            "Cloudinary destroy failed ({}): {}",
+            status,
+            text
+        ));
+    }
+
+    // Cloudinary answers 200 with {"result":"not found"} for unknown ids; treat that as already gone.
+    let json: serde_json::Value = serde_json::from_str(&text).map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to parse Cloudinary response: {} | Error: {}",
+            text,
+            e
+        )
+    })?;
+
+    match json.get("result").and_then(|result| result.as_str()) {
+        Some("ok") | Some("not found") => Ok(()),
+        _ => Err(anyhow::anyhow!(
+            "Cloudinary destroy failed for {}: {}",
+            public_id,
+            text
+        )),
+    }
+}
@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE mission_comments (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    brawler_id INTEGER NOT NULL,
+    content TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    mission_comments
+ADD
+    CONSTRAINT fk_comment_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD
+    CONSTRAINT fk_comment_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id);
+
+CREATE INDEX idx_mission_comments_mission_id ON mission_comments (mission_id, created_at);
+
+SELECT diesel_manage_updated_at('mission_comments');
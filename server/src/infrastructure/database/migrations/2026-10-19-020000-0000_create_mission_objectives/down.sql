@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE missions
+DROP COLUMN require_objectives;
+
+DROP TABLE IF EXISTS mission_objectives;
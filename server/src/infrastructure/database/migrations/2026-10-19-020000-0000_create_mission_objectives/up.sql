@@ -0,0 +1,25 @@
+-- Your SQL goes here
+CREATE TABLE mission_objectives (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    "position" INTEGER NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    completed_at TIMESTAMP,
+    completed_by INTEGER,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    mission_objectives
+ADD
+    CONSTRAINT fk_objective_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD
+    CONSTRAINT fk_objective_brawler FOREIGN KEY (completed_by) REFERENCES brawlers(id);
+
+CREATE INDEX idx_mission_objectives_mission_id ON mission_objectives (mission_id, "position");
+
+ALTER TABLE missions
+ADD COLUMN require_objectives BOOLEAN NOT NULL DEFAULT FALSE;
+
+SELECT diesel_manage_updated_at('mission_objectives');
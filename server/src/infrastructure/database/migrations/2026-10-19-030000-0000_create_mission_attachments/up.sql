@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE mission_attachments (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    uploaded_by INTEGER NOT NULL,
+    url VARCHAR(512) NOT NULL,
+    public_id VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    mission_attachments
+ADD
+    CONSTRAINT fk_attachment_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD
+    CONSTRAINT fk_attachment_brawler FOREIGN KEY (uploaded_by) REFERENCES brawlers(id);
+
+CREATE INDEX idx_mission_attachments_mission_id ON mission_attachments (mission_id);
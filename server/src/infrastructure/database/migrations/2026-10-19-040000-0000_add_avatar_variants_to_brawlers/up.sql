@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE brawlers
+ADD COLUMN avatar_variants JSONB;
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP VIEW IF EXISTS brawler_stats;
+DROP TABLE IF EXISTS crew_departures;
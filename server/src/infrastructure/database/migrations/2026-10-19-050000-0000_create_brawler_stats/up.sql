@@ -0,0 +1,37 @@
+-- Your SQL goes here
+CREATE TABLE crew_departures (
+    id SERIAL PRIMARY KEY,
+    mission_id INT4 NOT NULL,
+    brawler_id INT4 NOT NULL,
+    reason VARCHAR(16) NOT NULL,
+    departed_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT fk_departure_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+    CONSTRAINT fk_departure_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id)
+);
+
+CREATE INDEX idx_crew_departures_brawler_id ON crew_departures (brawler_id);
+
+-- Statuses are the `MissionStatuses` display values; deleted missions are not counted.
+CREATE VIEW brawler_stats AS
+WITH participation AS (
+    SELECT m.chief_id AS brawler_id, m.status, 'chief' AS role
+    FROM missions m
+    WHERE m.deleted_at IS NULL
+    UNION ALL
+    SELECT cm.brawler_id, m.status, 'crew' AS role
+    FROM crew_memberships cm
+    INNER JOIN missions m ON m.id = cm.mission_id
+    WHERE m.deleted_at IS NULL
+)
+SELECT
+    b.id AS brawler_id,
+    COUNT(p.role) FILTER (WHERE p.role = 'chief') AS missions_led,
+    COUNT(p.role) FILTER (WHERE p.role = 'crew') AS missions_joined,
+    COUNT(p.role) FILTER (WHERE p.role = 'chief' AND p.status = 'Completed') AS completed_as_chief,
+    COUNT(p.role) FILTER (WHERE p.role = 'chief' AND p.status = 'Failed') AS failed_as_chief,
+    COUNT(p.role) FILTER (WHERE p.role = 'crew' AND p.status = 'Completed') AS completed_as_crew,
+    COUNT(p.role) FILTER (WHERE p.role = 'crew' AND p.status = 'Failed') AS failed_as_crew,
+    (SELECT COUNT(*) FROM crew_departures d WHERE d.brawler_id = b.id) AS abandoned
+FROM brawlers b
+LEFT JOIN participation p ON p.brawler_id = b.id
+GROUP BY b.id;
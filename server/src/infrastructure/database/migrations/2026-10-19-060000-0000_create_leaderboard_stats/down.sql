@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+DROP MATERIALIZED VIEW IF EXISTS leaderboard_stats;
@@ -0,0 +1,34 @@
+-- Your SQL goes here
+-- One row per brawler and period. A mission counts towards a period when it was last
+-- updated inside it, which for finished missions is when they were completed/failed.
+-- Refreshed by the leaderboard job with REFRESH MATERIALIZED VIEW CONCURRENTLY.
+CREATE MATERIALIZED VIEW leaderboard_stats AS
+WITH periods (period, since) AS (
+    VALUES
+        ('week', (now() - INTERVAL '7 days')::TIMESTAMP),
+        ('month', (now() - INTERVAL '30 days')::TIMESTAMP),
+        ('all', '-infinity'::TIMESTAMP)
+), participation AS (
+    SELECT m.chief_id AS brawler_id, m.status, m.updated_at, 'chief' AS role
+    FROM missions m
+    WHERE m.deleted_at IS NULL
+    UNION ALL
+    SELECT cm.brawler_id, m.status, m.updated_at, 'crew' AS role
+    FROM crew_memberships cm
+    INNER JOIN missions m ON m.id = cm.mission_id
+    WHERE m.deleted_at IS NULL
+)
+SELECT
+    b.id AS brawler_id,
+    p.period,
+    COUNT(x.role) FILTER (WHERE x.status = 'Completed') AS completed,
+    COUNT(x.role) FILTER (WHERE x.status = 'Failed') AS failed,
+    COUNT(x.role) FILTER (WHERE x.role = 'chief') AS led,
+    COUNT(x.role) AS activity,
+    now()::TIMESTAMP AS refreshed_at
+FROM brawlers b
+CROSS JOIN periods p
+LEFT JOIN participation x ON x.brawler_id = b.id AND x.updated_at >= p.since
+GROUP BY b.id, p.period;
+
+CREATE UNIQUE INDEX idx_leaderboard_stats_period_brawler ON leaderboard_stats (period, brawler_id);
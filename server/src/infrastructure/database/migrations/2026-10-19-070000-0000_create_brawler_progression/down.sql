@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS brawler_achievements;
+DROP TABLE IF EXISTS xp_awards;
+
+ALTER TABLE brawlers
+DROP COLUMN xp;
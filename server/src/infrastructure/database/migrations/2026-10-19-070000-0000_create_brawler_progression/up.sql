@@ -0,0 +1,22 @@
+-- Your SQL goes here
+ALTER TABLE brawlers
+ADD COLUMN xp INT8 NOT NULL DEFAULT 0;
+
+CREATE TABLE xp_awards (
+    id SERIAL PRIMARY KEY,
+    brawler_id INT4 NOT NULL,
+    mission_id INT4 NOT NULL,
+    amount INT4 NOT NULL,
+    awarded_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT fk_xp_award_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+    CONSTRAINT fk_xp_award_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+    CONSTRAINT uq_xp_award_brawler_mission UNIQUE (brawler_id, mission_id)
+);
+
+CREATE TABLE brawler_achievements (
+    brawler_id INT4 NOT NULL,
+    achievement VARCHAR(64) NOT NULL,
+    unlocked_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (brawler_id, achievement),
+    CONSTRAINT fk_achievement_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id)
+);
@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE brawler_ratings (
+    id SERIAL PRIMARY KEY,
+    mission_id INT4 NOT NULL,
+    rater_id INT4 NOT NULL,
+    ratee_id INT4 NOT NULL,
+    score INT4 NOT NULL,
+    comment TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT fk_rating_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+    CONSTRAINT fk_rating_rater FOREIGN KEY (rater_id) REFERENCES brawlers(id),
+    CONSTRAINT fk_rating_ratee FOREIGN KEY (ratee_id) REFERENCES brawlers(id),
+    CONSTRAINT uq_rating_mission_pair UNIQUE (mission_id, rater_id, ratee_id),
+    CONSTRAINT ck_rating_score CHECK (score BETWEEN 1 AND 5),
+    CONSTRAINT ck_rating_not_self CHECK (rater_id <> ratee_id)
+);
+
+CREATE INDEX idx_brawler_ratings_ratee_id ON brawler_ratings (ratee_id);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS password_reset_tokens;
+
+ALTER TABLE brawlers
+DROP COLUMN email;
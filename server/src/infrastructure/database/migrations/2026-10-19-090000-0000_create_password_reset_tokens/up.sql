@@ -0,0 +1,15 @@
+-- Your SQL goes here
+ALTER TABLE brawlers
+ADD COLUMN email VARCHAR(255) UNIQUE;
+
+CREATE TABLE password_reset_tokens (
+    id SERIAL PRIMARY KEY,
+    brawler_id INT4 NOT NULL,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    expires_at TIMESTAMP NOT NULL,
+    used_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT fk_reset_token_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id)
+);
+
+CREATE INDEX idx_password_reset_tokens_brawler_id ON password_reset_tokens (brawler_id);
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE login_failures (
+    id SERIAL PRIMARY KEY,
+    username VARCHAR(255) NOT NULL,
+    ip_address VARCHAR(64),
+    reason VARCHAR(32) NOT NULL,
+    attempted_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_login_failures_username ON login_failures (username, attempted_at);
+
+CREATE TABLE login_throttles (
+    throttle_key VARCHAR(320) PRIMARY KEY,
+    failures INT4 NOT NULL DEFAULT 0,
+    locked_until TIMESTAMP,
+    last_failure_at TIMESTAMP NOT NULL DEFAULT now()
+);
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE brawler_identities (
+    id SERIAL PRIMARY KEY,
+    brawler_id INT4 NOT NULL,
+    issuer VARCHAR(255) NOT NULL,
+    subject VARCHAR(255) NOT NULL,
+    email VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_login_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT fk_identity_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+    CONSTRAINT uq_identity_issuer_subject UNIQUE (issuer, subject)
+);
+
+CREATE INDEX idx_brawler_identities_brawler_id ON brawler_identities (brawler_id);
@@ -0,0 +1,22 @@
+-- Your SQL goes here
+CREATE TABLE personal_access_tokens (
+    id SERIAL PRIMARY KEY,
+    brawler_id INT4 NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    token_prefix VARCHAR(16) NOT NULL,
+    token_hash VARCHAR(255) NOT NULL,
+    scopes TEXT[] NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    expires_at TIMESTAMP,
+    last_used_at TIMESTAMP,
+    revoked_at TIMESTAMP,
+    CONSTRAINT fk_personal_access_token_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+    CONSTRAINT uq_personal_access_token_prefix UNIQUE (token_prefix)
+);
+
+CREATE INDEX idx_personal_access_tokens_brawler_id ON personal_access_tokens (brawler_id);
+
+-- A name can be reused once the token that had it is revoked.
+CREATE UNIQUE INDEX uq_personal_access_token_active_name
+    ON personal_access_tokens (brawler_id, name)
+    WHERE revoked_at IS NULL;
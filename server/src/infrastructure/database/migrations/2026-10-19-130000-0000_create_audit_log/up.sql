@@ -0,0 +1,19 @@
+-- Your SQL goes here
+-- No foreign keys: entries must outlive purged missions and anything else they point at.
+CREATE TABLE audit_log (
+    id BIGSERIAL PRIMARY KEY,
+    actor_id INT4 NOT NULL,
+    action VARCHAR(50) NOT NULL,
+    target_type VARCHAR(50) NOT NULL,
+    target_id INT4 NOT NULL,
+    mission_id INT4,
+    before JSONB,
+    after JSONB,
+    request_id VARCHAR(36),
+    ip_address VARCHAR(45),
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_audit_log_created_at ON audit_log (created_at);
+CREATE INDEX idx_audit_log_actor_id ON audit_log (actor_id, created_at);
+CREATE INDEX idx_audit_log_mission_id ON audit_log (mission_id, created_at);
@@ -0,0 +1,77 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{insert_into, pg::Pg, prelude::*};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::audit_log::{AddAuditLogEntity, AuditLogEntity},
+        repositories::audit_log::AuditLogRepository,
+        value_objects::audit_log::AuditLogQuery,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::audit_log},
+};
+
+pub struct AuditLogPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl AuditLogPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+/// The entries matching the query's filters; absent filters match everything.
+fn filtered(query: &AuditLogQuery) -> audit_log::BoxedQuery<'static, Pg> {
+    let mut statement = audit_log::table.into_boxed();
+
+    if let Some(actor_id) = query.actor_id {
+        statement = statement.filter(audit_log::actor_id.eq(actor_id));
+    }
+    if let Some(mission_id) = query.mission_id {
+        statement = statement.filter(audit_log::mission_id.eq(mission_id));
+    }
+    if let Some(from) = query.from {
+        statement = statement.filter(audit_log::created_at.ge(from));
+    }
+    if let Some(to) = query.to {
+        statement = statement.filter(audit_log::created_at.lt(to));
+    }
+
+    statement
+}
+
+#[async_trait]
+impl AuditLogRepository for AuditLogPostgres {
+    async fn record(&self, add_audit_log_entity: AddAuditLogEntity) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        insert_into(audit_log::table)
+            .values(add_audit_log_entity)
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn search(&self, query: &AuditLogQuery) -> Result<Vec<AuditLogEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = filtered(query)
+            .order((audit_log::created_at.desc(), audit_log::id.desc()))
+            .limit(query.per_page())
+            .offset(query.offset())
+            .select(AuditLogEntity::as_select())
+            .load::<AuditLogEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn counting(&self, query: &AuditLogQuery) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = filtered(query).count().get_result::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+}
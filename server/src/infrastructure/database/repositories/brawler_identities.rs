@@ -0,0 +1,113 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{
+    insert_into,
+    prelude::*,
+    result::{DatabaseErrorKind, Error},
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::{brawler_identities::AddBrawlerIdentityEntity, brawlers::RegisterBrawlerEntity},
+        repositories::brawler_identities::BrawlerIdentityRepository,
+        value_objects::external_identity::{ExternalIdentity, IDENTITY_ALREADY_LINKED},
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawler_identities, brawlers},
+    },
+};
+
+pub struct BrawlerIdentityPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl BrawlerIdentityPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+fn identity_entity(brawler_id: i32, identity: ExternalIdentity) -> AddBrawlerIdentityEntity {
+    AddBrawlerIdentityEntity {
+        brawler_id,
+        issuer: identity.issuer,
+        subject: identity.subject,
+        email: identity.email,
+    }
+}
+
+#[async_trait]
+impl BrawlerIdentityRepository for BrawlerIdentityPostgres {
+    async fn login(&self, identity: ExternalIdentity) -> Result<Option<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(
+            brawler_identities::table
+                .filter(brawler_identities::issuer.eq(&identity.issuer))
+                .filter(brawler_identities::subject.eq(&identity.subject)),
+        )
+        .set((
+            brawler_identities::last_login_at.eq(diesel::dsl::now),
+            brawler_identities::email.eq(&identity.email),
+        ))
+        .returning(brawler_identities::brawler_id)
+        .get_result::<i32>(&mut conn)
+        .optional()?;
+
+        Ok(result)
+    }
+
+    async fn link(&self, brawler_id: i32, identity: ExternalIdentity) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let linked_brawler_id = brawler_identities::table
+            .filter(brawler_identities::issuer.eq(&identity.issuer))
+            .filter(brawler_identities::subject.eq(&identity.subject))
+            .select(brawler_identities::brawler_id)
+            .first::<i32>(&mut conn)
+            .optional()?;
+
+        match linked_brawler_id {
+            Some(linked_brawler_id) if linked_brawler_id == brawler_id => return Ok(()),
+            Some(_) => return Err(anyhow::anyhow!(IDENTITY_ALREADY_LINKED)),
+            None => {}
+        }
+
+        let result = insert_into(brawler_identities::table)
+            .values(identity_entity(brawler_id, identity))
+            .execute(&mut conn);
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _)) => {
+                Err(anyhow::anyhow!(IDENTITY_ALREADY_LINKED))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn register(
+        &self,
+        register_brawler_entity: RegisterBrawlerEntity,
+        identity: ExternalIdentity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let brawler_id = conn.transaction::<i32, Error, _>(|conn| {
+            let brawler_id = insert_into(brawlers::table)
+                .values(&register_brawler_entity)
+                .returning(brawlers::id)
+                .get_result::<i32>(conn)?;
+
+            insert_into(brawler_identities::table)
+                .values(identity_entity(brawler_id, identity))
+                .execute(conn)?;
+
+            Ok(brawler_id)
+        })?;
+
+        Ok(brawler_id)
+    }
+}
@@ -0,0 +1,57 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{
+    prelude::*,
+    result::{DatabaseErrorKind, Error},
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::brawler_ratings::{AddBrawlerRatingEntity, BrawlerRatingEntity},
+        repositories::brawler_ratings::BrawlerRatingRepository,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::brawler_ratings},
+};
+
+pub struct BrawlerRatingPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl BrawlerRatingPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl BrawlerRatingRepository for BrawlerRatingPostgres {
+    async fn add(&self, add_rating_entity: AddBrawlerRatingEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::insert_into(brawler_ratings::table)
+            .values(add_rating_entity)
+            .returning(brawler_ratings::id)
+            .get_result::<i32>(&mut conn);
+
+        match result {
+            Ok(id) => Ok(id),
+            Err(Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _)) => Err(
+                anyhow::anyhow!("You have already rated this brawler for this mission"),
+            ),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn gets(&self, mission_id: i32) -> Result<Vec<BrawlerRatingEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = brawler_ratings::table
+            .filter(brawler_ratings::mission_id.eq(mission_id))
+            .order(brawler_ratings::created_at.asc())
+            .select(BrawlerRatingEntity::as_select())
+            .load::<BrawlerRatingEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+}
@@ -3,29 +3,72 @@ use async_trait::async_trait;
 use diesel::insert_into;
 use diesel::prelude::*;
 use std::sync::Arc;
+use tracing::warn;
 
 use crate::{
     domain::{
-        entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
-        repositories::brawlers::BrawlerRepository,
+        entities::{
+            brawler_achievements::BrawlerAchievementEntity,
+            brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+        },
+        repositories::{brawlers::BrawlerRepository, image_storage::ImageStorage},
         value_objects::{
             base64_image::Base64Image,
-            uploaded_image::{UploadImageOptions, UploadedImage},
+            brawler_model::BrawlerProfileStats,
+            progression::{Achievement, UnlockedAchievement},
+            uploaded_image::{AvatarVariant, UploadImageOptions, UploadedAvatar},
         },
     },
-    infrastructure::{
-        cloudinary,
-        database::{postgresql_connection::PgPoolSquad, schema::brawlers},
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawler_achievements, brawlers},
     },
 };
 
 pub struct BrawlerPostgres {
     db_pool: Arc<PgPoolSquad>,
+    image_storage: Arc<dyn ImageStorage>,
 }
 
 impl BrawlerPostgres {
-    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
-        Self { db_pool }
+    pub fn new(db_pool: Arc<PgPoolSquad>, image_storage: Arc<dyn ImageStorage>) -> Self {
+        Self {
+            db_pool,
+            image_storage,
+        }
+    }
+
+    /// Every stored public id of the brawler's avatar, the primary one included.
+    fn avatar_public_ids(&self, brawler_id: i32) -> Result<Vec<String>> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let (public_id, variants) = brawlers::table
+            .filter(brawlers::id.eq(brawler_id))
+            .select((brawlers::avatar_public_id, brawlers::avatar_variants))
+            .first::<(Option<String>, Option<serde_json::Value>)>(&mut connection)?;
+
+        let mut public_ids = variants
+            .map(serde_json::from_value::<Vec<AvatarVariant>>)
+            .transpose()?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|variant| variant.public_id)
+            .collect::<Vec<String>>();
+        if let Some(public_id) = public_id
+            && !public_ids.contains(&public_id)
+        {
+            public_ids.push(public_id);
+        }
+
+        Ok(public_ids)
+    }
+
+    async fn destroy_all(&self, public_ids: Vec<String>) -> Result<()> {
+        for public_id in public_ids {
+            self.image_storage.destroy(public_id).await?;
+        }
+
+        Ok(())
     }
 }
 
@@ -42,35 +85,134 @@ impl BrawlerRepository for BrawlerPostgres {
         Ok(result)
     }
 
-    async fn find_by_username(&self, username: String) -> Result<BrawlerEntity> {
+    async fn find_by_username(&self, username: String) -> Result<Option<BrawlerEntity>> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
 
         let result = brawlers::table
             .filter(brawlers::username.eq(username))
             .select(BrawlerEntity::as_select())
+            .first::<BrawlerEntity>(&mut connection)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn find_by_id(&self, brawler_id: i32) -> Result<BrawlerEntity> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let result = brawlers::table
+            .filter(brawlers::id.eq(brawler_id))
+            .select(BrawlerEntity::as_select())
             .first::<BrawlerEntity>(&mut connection)?;
 
         Ok(result)
     }
 
+    async fn update_password(&self, brawler_id: i32, hashed_password: String) -> Result<()> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(brawlers::table.filter(brawlers::id.eq(brawler_id)))
+            .set(brawlers::password.eq(hashed_password))
+            .execute(&mut connection)?;
+
+        Ok(())
+    }
+
     async fn upload_avatar(
         &self,
         brawler_id: i32,
-        base64_image: Base64Image,
+        variants: Vec<(u32, Base64Image)>,
         option: UploadImageOptions,
-    ) -> Result<UploadedImage> {
-        let uploaded_image = cloudinary::upload(base64_image, option).await?;
+    ) -> Result<UploadedAvatar> {
+        let previous_public_ids = self.avatar_public_ids(brawler_id)?;
+
+        let mut uploaded_variants: Vec<AvatarVariant> = Vec::with_capacity(variants.len());
+        for (size, base64_image) in variants {
+            let variant_option = UploadImageOptions {
+                public_id: option
+                    .public_id
+                    .as_ref()
+                    .map(|public_id| format!("{}_{}", public_id, size)),
+                ..option.clone()
+            };
+
+            match self
+                .image_storage
+                .upload(base64_image, variant_option)
+                .await
+            {
+                Ok(uploaded) => uploaded_variants.push(AvatarVariant {
+                    size,
+                    url: uploaded.url,
+                    public_id: uploaded.public_id,
+                }),
+                Err(e) => {
+                    let public_ids = uploaded_variants.into_iter().map(|v| v.public_id).collect();
+                    if let Err(e) = self.destroy_all(public_ids).await {
+                        warn!(
+                            "Failed to clean up avatar variants of brawler {}: {}",
+                            brawler_id, e
+                        );
+                    }
+                    return Err(e);
+                }
+            }
+        }
+
+        let primary = uploaded_variants
+            .iter()
+            .max_by_key(|variant| variant.size)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No avatar variants to upload"))?;
+
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(brawlers::table.filter(brawlers::id.eq(brawler_id)))
+            .set((
+                brawlers::avatar_url.eq(Some(primary.url.clone())),
+                brawlers::avatar_public_id.eq(Some(primary.public_id.clone())),
+                brawlers::avatar_variants.eq(Some(serde_json::to_value(&uploaded_variants)?)),
+            ))
+            .execute(&mut connection)?;
+
+        // The new avatar is already saved, so a failed cleanup only leaves orphaned assets.
+        let stale_public_ids = previous_public_ids
+            .into_iter()
+            .filter(|public_id| !uploaded_variants.iter().any(|v| &v.public_id == public_id))
+            .collect();
+        if let Err(e) = self.destroy_all(stale_public_ids).await {
+            warn!(
+                "Failed to destroy old avatar of brawler {}: {}",
+                brawler_id, e
+            );
+        }
+
+        Ok(UploadedAvatar {
+            url: primary.url,
+            public_id: primary.public_id,
+            variants: uploaded_variants,
+        })
+    }
+
+    async fn remove_avatar(&self, brawler_id: i32) -> Result<()> {
+        let public_ids = self.avatar_public_ids(brawler_id)?;
+        if public_ids.is_empty() {
+            return Err(anyhow::anyhow!("Brawler has no avatar to remove"));
+        }
+
+        self.destroy_all(public_ids).await?;
 
         let mut connection = Arc::clone(&self.db_pool).get()?;
 
         diesel::update(brawlers::table.filter(brawlers::id.eq(brawler_id)))
             .set((
-                brawlers::avatar_url.eq(Some(uploaded_image.url.clone())),
-                brawlers::avatar_public_id.eq(Some(uploaded_image.public_id.clone())),
+                brawlers::avatar_url.eq(None::<String>),
+                brawlers::avatar_public_id.eq(None::<String>),
+                brawlers::avatar_variants.eq(None::<serde_json::Value>),
             ))
             .execute(&mut connection)?;
 
-        Ok(uploaded_image)
+        Ok(())
     }
 
     async fn get_brawlers_by_mission_id(&self, mission_id: i32) -> Result<Vec<BrawlerEntity>> {
@@ -131,6 +273,9 @@ impl BrawlerRepository for BrawlerPostgres {
                 m.max_crew,
                 m.deadline,
                 m.duration,
+                m.require_objectives,
+                (SELECT (COUNT(*) FILTER (WHERE mo.completed_at IS NOT NULL) * 100 / NULLIF(COUNT(*), 0))::INT4
+                    FROM mission_objectives mo WHERE mo.mission_id = m.id) AS progress,
                 m.created_at,
                 m.updated_at
             FROM missions m
@@ -148,4 +293,107 @@ impl BrawlerRepository for BrawlerPostgres {
 
         Ok(result)
     }
+
+    async fn get_recent_missions(
+        &self,
+        brawler_id: i32,
+        limit: i64,
+    ) -> Result<Vec<crate::domain::value_objects::mission_model::MissionModel>> {
+        use crate::domain::value_objects::mission_model::MissionModel;
+        use diesel::sql_types::{BigInt, Int4};
+
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+            SELECT 
+                m.id,
+                m.name,
+                m.description,
+                m.status,
+                m.chief_id,
+                b.display_name AS chief_display_name,
+                (SELECT COUNT(*) FROM crew_memberships cm WHERE cm.mission_id = m.id) AS crew_count,
+                m.max_crew,
+                m.deadline,
+                m.duration,
+                m.require_objectives,
+                (SELECT (COUNT(*) FILTER (WHERE mo.completed_at IS NOT NULL) * 100 / NULLIF(COUNT(*), 0))::INT4
+                    FROM mission_objectives mo WHERE mo.mission_id = m.id) AS progress,
+                m.created_at,
+                m.updated_at
+            FROM missions m
+            INNER JOIN brawlers b ON b.id = m.chief_id
+            WHERE (m.chief_id = $1 OR EXISTS (
+                SELECT 1 FROM crew_memberships cm_user
+                WHERE cm_user.mission_id = m.id AND cm_user.brawler_id = $1
+            )) AND m.deleted_at IS NULL
+            ORDER BY m.updated_at DESC
+            LIMIT $2
+        "#;
+
+        let result = diesel::sql_query(sql)
+            .bind::<Int4, _>(brawler_id)
+            .bind::<BigInt, _>(limit)
+            .load::<MissionModel>(&mut connection)?;
+
+        Ok(result)
+    }
+
+    async fn get_profile_stats(&self, brawler_id: i32) -> Result<Option<BrawlerProfileStats>> {
+        use diesel::sql_types::Int4;
+
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+            SELECT
+                b.id,
+                b.display_name,
+                b.avatar_url,
+                b.created_at AS joined_at,
+                b.xp,
+                s.missions_led,
+                s.missions_joined,
+                s.completed_as_chief,
+                s.failed_as_chief,
+                s.completed_as_crew,
+                s.failed_as_crew,
+                s.abandoned,
+                r.ratings_count,
+                r.ratings_sum
+            FROM brawlers b
+            INNER JOIN brawler_stats s ON s.brawler_id = b.id
+            CROSS JOIN LATERAL (
+                SELECT COUNT(*) AS ratings_count, COALESCE(SUM(br.score), 0)::INT8 AS ratings_sum
+                FROM brawler_ratings br
+                WHERE br.ratee_id = b.id
+            ) r
+            WHERE b.id = $1
+        "#;
+
+        let result = diesel::sql_query(sql)
+            .bind::<Int4, _>(brawler_id)
+            .get_result::<BrawlerProfileStats>(&mut connection)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn get_achievements(&self, brawler_id: i32) -> Result<Vec<UnlockedAchievement>> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let results = brawler_achievements::table
+            .filter(brawler_achievements::brawler_id.eq(brawler_id))
+            .order(brawler_achievements::unlocked_at.asc())
+            .select(BrawlerAchievementEntity::as_select())
+            .load::<BrawlerAchievementEntity>(&mut connection)?;
+
+        // Codes of retired achievements are skipped rather than failing the whole list.
+        Ok(results
+            .into_iter()
+            .filter_map(|entity| {
+                Achievement::from_code(&entity.achievement)
+                    .map(|achievement| UnlockedAchievement::new(achievement, entity.unlocked_at))
+            })
+            .collect())
+    }
 }
@@ -1,14 +1,18 @@
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::delete, insert_into};
+use diesel::{Connection, ExpressionMethods, RunQueryDsl, dsl::delete, insert_into};
 use std::sync::Arc;
 
 use crate::{
     domain::{
-        entities::crew_memberships::CrewMemberShips,
+        entities::{crew_departures::AddCrewDepartureEntity, crew_memberships::CrewMemberShips},
         repositories::crew_operation::CrewOperationRepository,
+        value_objects::departure_reasons::DepartureReasons,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{crew_departures, crew_memberships},
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::crew_memberships},
 };
 
 pub struct CrewOperationPostgres {
@@ -31,12 +35,32 @@ impl CrewOperationRepository for CrewOperationPostgres {
         Ok(())
     }
 
-    async fn leave(&self, crew_member_ships: CrewMemberShips) -> Result<()> {
+    async fn leave(
+        &self,
+        crew_member_ships: CrewMemberShips,
+        reason: DepartureReasons,
+    ) -> Result<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
-        let affected_rows = delete(crew_memberships::table)
-            .filter(crew_memberships::brawler_id.eq(crew_member_ships.brawler_id))
-            .filter(crew_memberships::mission_id.eq(crew_member_ships.mission_id))
-            .execute(&mut conn)?;
+
+        let affected_rows = conn.transaction::<usize, diesel::result::Error, _>(|conn| {
+            let affected_rows = delete(crew_memberships::table)
+                .filter(crew_memberships::brawler_id.eq(crew_member_ships.brawler_id))
+                .filter(crew_memberships::mission_id.eq(crew_member_ships.mission_id))
+                .execute(conn)?;
+
+            // Departures feed the `abandoned` counter of the brawler_stats view.
+            if affected_rows > 0 {
+                insert_into(crew_departures::table)
+                    .values(AddCrewDepartureEntity {
+                        mission_id: crew_member_ships.mission_id,
+                        brawler_id: crew_member_ships.brawler_id,
+                        reason: reason.to_string(),
+                    })
+                    .execute(conn)?;
+            }
+
+            std::result::Result::Ok(affected_rows)
+        })?;
 
         if affected_rows == 0 {
             return Err(anyhow::anyhow!(
@@ -56,6 +56,9 @@ impl DashboardRepository for DashboardPostgres {
                 m.max_crew,
                 m.deadline,
                 m.duration,
+                m.require_objectives,
+                (SELECT (COUNT(*) FILTER (WHERE mo.completed_at IS NOT NULL) * 100 / NULLIF(COUNT(*), 0))::INT4
+                    FROM mission_objectives mo WHERE mo.mission_id = m.id) AS progress,
                 m.created_at,
                 m.updated_at
             FROM missions m
@@ -79,13 +82,19 @@ impl DashboardRepository for DashboardPostgres {
 
         let sql = r#"
             SELECT
-                (SELECT COUNT(*) FROM missions WHERE chief_id = $1 AND deleted_at IS NULL) AS my_missions_count,
-                (SELECT COUNT(*) FROM crew_memberships WHERE brawler_id = $1) AS joined_missions_count,
-                (SELECT COUNT(*) FROM crew_memberships cm
-                    INNER JOIN missions m ON m.id = cm.mission_id
-                    WHERE cm.brawler_id = $1 AND m.status = 'Completed') AS success_count,
-                (SELECT COUNT(*) FROM crew_memberships WHERE brawler_id = $1) +
-                (SELECT COUNT(*) FROM missions WHERE chief_id = $1 AND deleted_at IS NULL) AS total_participated
+                s.missions_led AS my_missions_count,
+                s.missions_joined AS joined_missions_count,
+                s.completed_as_chief + s.completed_as_crew AS success_count,
+                s.missions_led + s.missions_joined AS total_participated,
+                s.missions_led,
+                s.missions_joined,
+                s.completed_as_chief,
+                s.failed_as_chief,
+                s.completed_as_crew,
+                s.failed_as_crew,
+                s.abandoned
+            FROM brawler_stats s
+            WHERE s.brawler_id = $1
         "#;
 
         let result = diesel::sql_query(sql)
@@ -116,6 +125,9 @@ impl DashboardRepository for DashboardPostgres {
                 m.max_crew,
                 m.deadline,
                 m.duration,
+                m.require_objectives,
+                (SELECT (COUNT(*) FILTER (WHERE mo.completed_at IS NOT NULL) * 100 / NULLIF(COUNT(*), 0))::INT4
+                    FROM mission_objectives mo WHERE mo.mission_id = m.id) AS progress,
                 m.created_at,
                 m.updated_at
             FROM missions m
@@ -158,6 +170,9 @@ impl DashboardRepository for DashboardPostgres {
                 m.max_crew,
                 m.deadline,
                 m.duration,
+                m.require_objectives,
+                (SELECT (COUNT(*) FILTER (WHERE mo.completed_at IS NOT NULL) * 100 / NULLIF(COUNT(*), 0))::INT4
+                    FROM mission_objectives mo WHERE mo.mission_id = m.id) AS progress,
                 m.created_at,
                 m.updated_at
             FROM missions m
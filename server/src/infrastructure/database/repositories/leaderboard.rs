@@ -0,0 +1,93 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use crate::domain::{
+    repositories::leaderboard::LeaderboardRepository,
+    value_objects::leaderboard::{LeaderboardCategory, LeaderboardEntry, LeaderboardPeriod},
+};
+use crate::infrastructure::database::postgresql_connection::PgPoolSquad;
+
+pub struct LeaderboardPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl LeaderboardPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl LeaderboardRepository for LeaderboardPostgres {
+    async fn ranking(
+        &self,
+        category: LeaderboardCategory,
+        period: LeaderboardPeriod,
+        min_sample: i64,
+        limit: i64,
+    ) -> Result<Vec<LeaderboardEntry>> {
+        use diesel::sql_types::{BigInt, Text};
+
+        let mut conn = self.db_pool.get()?;
+
+        // Only fixed expressions are interpolated, every value is bound.
+        let (metric, qualifies) = match category {
+            LeaderboardCategory::Completed => ("s.completed", "s.completed > 0"),
+            LeaderboardCategory::SuccessRate => (
+                "s.success_rate",
+                "s.completed + s.failed >= GREATEST($2, 1)",
+            ),
+            LeaderboardCategory::Led => ("s.led", "s.led > 0"),
+            LeaderboardCategory::Activity => ("s.activity", "s.activity > 0"),
+        };
+
+        let sql = format!(
+            r#"
+            SELECT
+                RANK() OVER (ORDER BY {metric} DESC) AS rank,
+                s.brawler_id,
+                s.display_name,
+                s.avatar_url,
+                s.completed,
+                s.failed,
+                s.led,
+                s.activity,
+                s.success_rate,
+                s.refreshed_at
+            FROM (
+                SELECT
+                    ls.*,
+                    b.display_name,
+                    b.avatar_url,
+                    ROUND(ls.completed * 100.0 / NULLIF(ls.completed + ls.failed, 0), 1)::FLOAT8
+                        AS success_rate
+                FROM leaderboard_stats ls
+                INNER JOIN brawlers b ON b.id = ls.brawler_id
+                WHERE ls.period = $1
+            ) s
+            WHERE {qualifies}
+            ORDER BY rank, s.display_name
+            LIMIT $3
+        "#
+        );
+
+        let results = diesel::sql_query(sql)
+            .bind::<Text, _>(period.as_key())
+            .bind::<BigInt, _>(min_sample)
+            .bind::<BigInt, _>(limit)
+            .load::<LeaderboardEntry>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn refresh(&self) -> Result<()> {
+        let mut conn = self.db_pool.get()?;
+
+        diesel::sql_query("REFRESH MATERIALIZED VIEW CONCURRENTLY leaderboard_stats")
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::*,
+    sql_types::{Timestamp, Varchar},
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::login_failures::AddLoginFailureEntity,
+        repositories::login_attempts::LoginAttemptRepository,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{login_failures, login_throttles},
+    },
+};
+
+#[derive(QueryableByName)]
+struct FailureCount {
+    #[diesel(sql_type = diesel::sql_types::Int4)]
+    failures: i32,
+}
+
+pub struct LoginAttemptPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl LoginAttemptPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl LoginAttemptRepository for LoginAttemptPostgres {
+    async fn add_failure(&self, add_login_failure_entity: AddLoginFailureEntity) -> Result<()> {
+        let mut conn = self.db_pool.get()?;
+
+        diesel::insert_into(login_failures::table)
+            .values(&add_login_failure_entity)
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn register_failure(
+        &self,
+        throttle_key: String,
+        window_start: NaiveDateTime,
+    ) -> Result<i32> {
+        let mut conn = self.db_pool.get()?;
+
+        // A single upsert, so concurrent attempts against the same key can't lose a count.
+        let result = diesel::sql_query(
+            r#"
+            INSERT INTO login_throttles (throttle_key, failures, last_failure_at)
+            VALUES ($1, 1, $3)
+            ON CONFLICT (throttle_key) DO UPDATE SET
+                failures = CASE
+                    WHEN login_throttles.last_failure_at < $2 THEN 1
+                    ELSE login_throttles.failures + 1
+                END,
+                last_failure_at = $3
+            RETURNING failures
+            "#,
+        )
+        .bind::<Varchar, _>(throttle_key)
+        .bind::<Timestamp, _>(window_start)
+        .bind::<Timestamp, _>(chrono::Utc::now().naive_utc())
+        .get_result::<FailureCount>(&mut conn)?;
+
+        Ok(result.failures)
+    }
+
+    async fn lock(&self, throttle_key: String, locked_until: NaiveDateTime) -> Result<()> {
+        let mut conn = self.db_pool.get()?;
+
+        diesel::update(
+            login_throttles::table.filter(login_throttles::throttle_key.eq(throttle_key)),
+        )
+        .set(login_throttles::locked_until.eq(locked_until))
+        .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn locked_until(&self, throttle_keys: Vec<String>) -> Result<Option<NaiveDateTime>> {
+        let mut conn = self.db_pool.get()?;
+        let now = chrono::Utc::now().naive_utc();
+
+        let result = login_throttles::table
+            .filter(login_throttles::throttle_key.eq_any(throttle_keys))
+            .filter(login_throttles::locked_until.gt(now))
+            .select(diesel::dsl::max(login_throttles::locked_until))
+            .first::<Option<NaiveDateTime>>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn reset(&self, throttle_key: String) -> Result<()> {
+        let mut conn = self.db_pool.get()?;
+
+        diesel::delete(
+            login_throttles::table.filter(login_throttles::throttle_key.eq(throttle_key)),
+        )
+        .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
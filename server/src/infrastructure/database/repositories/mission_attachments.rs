@@ -0,0 +1,112 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::mission_attachments::{AddMissionAttachmentEntity, MissionAttachmentEntity},
+        repositories::{
+            image_storage::ImageStorage, mission_attachments::MissionAttachmentRepository,
+        },
+        value_objects::{base64_image::Base64Image, uploaded_image::UploadImageOptions},
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::mission_attachments},
+};
+
+pub struct MissionAttachmentPostgres {
+    db_pool: Arc<PgPoolSquad>,
+    image_storage: Arc<dyn ImageStorage>,
+}
+
+impl MissionAttachmentPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>, image_storage: Arc<dyn ImageStorage>) -> Self {
+        Self {
+            db_pool,
+            image_storage,
+        }
+    }
+}
+
+#[async_trait]
+impl MissionAttachmentRepository for MissionAttachmentPostgres {
+    async fn upload(
+        &self,
+        mission_id: i32,
+        uploaded_by: i32,
+        base64_image: Base64Image,
+        option: UploadImageOptions,
+    ) -> Result<MissionAttachmentEntity> {
+        let uploaded_image = self.image_storage.upload(base64_image, option).await?;
+
+        let mut conn = self.db_pool.get()?;
+
+        let result = diesel::insert_into(mission_attachments::table)
+            .values(&AddMissionAttachmentEntity {
+                mission_id,
+                uploaded_by,
+                url: uploaded_image.url,
+                public_id: uploaded_image.public_id,
+            })
+            .returning(MissionAttachmentEntity::as_returning())
+            .get_result::<MissionAttachmentEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn gets(&self, mission_id: i32) -> Result<Vec<MissionAttachmentEntity>> {
+        let mut conn = self.db_pool.get()?;
+
+        let results = mission_attachments::table
+            .filter(mission_attachments::mission_id.eq(mission_id))
+            .order(mission_attachments::created_at.asc())
+            .select(MissionAttachmentEntity::as_select())
+            .load::<MissionAttachmentEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn find_by_id(&self, attachment_id: i32) -> Result<MissionAttachmentEntity> {
+        let mut conn = self.db_pool.get()?;
+
+        let result = mission_attachments::table
+            .filter(mission_attachments::id.eq(attachment_id))
+            .select(MissionAttachmentEntity::as_select())
+            .first::<MissionAttachmentEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn counting(&self, mission_id: i32) -> Result<i64> {
+        let mut conn = self.db_pool.get()?;
+
+        let count = mission_attachments::table
+            .filter(mission_attachments::mission_id.eq(mission_id))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(count)
+    }
+
+    async fn remove(&self, attachment_id: i32) -> Result<()> {
+        let attachment = self.find_by_id(attachment_id).await?;
+
+        self.image_storage.destroy(attachment.public_id).await?;
+
+        let mut conn = self.db_pool.get()?;
+
+        diesel::delete(mission_attachments::table)
+            .filter(mission_attachments::id.eq(attachment_id))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn remove_all(&self, mission_id: i32) -> Result<()> {
+        for attachment in self.gets(mission_id).await? {
+            self.remove(attachment.id).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::mission_comments::{AddMissionCommentEntity, MissionCommentEntity},
+        repositories::mission_comments::MissionCommentRepository,
+        value_objects::mission_comment_model::MissionCommentModel,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::mission_comments},
+};
+
+pub struct MissionCommentPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionCommentPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionCommentRepository for MissionCommentPostgres {
+    async fn add(&self, add_comment_entity: AddMissionCommentEntity) -> Result<i32> {
+        let mut conn = self.db_pool.get()?;
+
+        let result = diesel::insert_into(mission_comments::table)
+            .values(&add_comment_entity)
+            .returning(mission_comments::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn edit(&self, comment_id: i32, content: String) -> Result<i32> {
+        let mut conn = self.db_pool.get()?;
+
+        let result = diesel::update(mission_comments::table)
+            .filter(mission_comments::id.eq(comment_id))
+            .set(mission_comments::content.eq(content))
+            .returning(mission_comments::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn remove(&self, comment_id: i32) -> Result<()> {
+        let mut conn = self.db_pool.get()?;
+
+        let affected_rows = diesel::delete(mission_comments::table)
+            .filter(mission_comments::id.eq(comment_id))
+            .execute(&mut conn)?;
+
+        if affected_rows == 0 {
+            return Err(anyhow::anyhow!("Comment not found"));
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, comment_id: i32) -> Result<MissionCommentEntity> {
+        let mut conn = self.db_pool.get()?;
+
+        let result = mission_comments::table
+            .filter(mission_comments::id.eq(comment_id))
+            .select(MissionCommentEntity::as_select())
+            .first::<MissionCommentEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn gets(
+        &self,
+        mission_id: i32,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<MissionCommentModel>> {
+        use diesel::sql_types::{BigInt, Int4};
+
+        let mut conn = self.db_pool.get()?;
+
+        let sql = r#"
+            SELECT
+                c.id,
+                c.mission_id,
+                c.brawler_id,
+                b.display_name,
+                COALESCE(b.avatar_url, '') AS avatar_url,
+                c.content,
+                c.created_at,
+                c.updated_at
+            FROM mission_comments c
+            INNER JOIN brawlers b ON b.id = c.brawler_id
+            WHERE c.mission_id = $1
+            ORDER BY c.created_at ASC, c.id ASC
+            LIMIT $2 OFFSET $3
+        "#;
+
+        let results = diesel::sql_query(sql)
+            .bind::<Int4, _>(mission_id)
+            .bind::<BigInt, _>(limit)
+            .bind::<BigInt, _>(offset)
+            .load::<MissionCommentModel>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn counting(&self, mission_id: i32) -> Result<i64> {
+        let mut conn = self.db_pool.get()?;
+
+        let count = mission_comments::table
+            .filter(mission_comments::mission_id.eq(mission_id))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(count)
+    }
+}
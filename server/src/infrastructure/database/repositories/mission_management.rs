@@ -1,5 +1,6 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use diesel::prelude::*;
 use std::sync::Arc;
 
@@ -9,7 +10,13 @@ use crate::{
         repositories::mission_management::MissionManagementRepository,
         value_objects::mission_statuses::MissionStatuses,
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::missions},
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{
+            brawler_ratings, crew_departures, crew_memberships, mission_attachments,
+            mission_comments, mission_objectives, missions, xp_awards,
+        },
+    },
 };
 
 pub struct MissionManagementPostgres {
@@ -68,4 +75,107 @@ impl MissionManagementRepository for MissionManagementPostgres {
 
         Ok(())
     }
+
+    async fn restore(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+        let mut conn = self.db_pool.get()?;
+
+        let result = diesel::update(missions::table)
+            .filter(missions::id.eq(mission_id))
+            .filter(missions::chief_id.eq(chief_id))
+            .filter(missions::deleted_at.is_not_null())
+            .set(missions::deleted_at.eq(None::<NaiveDateTime>))
+            .returning(missions::id)
+            .get_result::<i32>(&mut conn)
+            .optional()?;
+
+        result.ok_or_else(|| {
+            anyhow::anyhow!(
+                "Mission not found in trash or you don't have permission to restore it!"
+            )
+        })
+    }
+
+    async fn gets_expired(&self, deleted_before: NaiveDateTime) -> Result<Vec<i32>> {
+        let mut conn = self.db_pool.get()?;
+
+        let results = missions::table
+            .filter(missions::deleted_at.lt(deleted_before))
+            .select(missions::id)
+            .load::<i32>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn is_expired(&self, mission_id: i32, deleted_before: NaiveDateTime) -> Result<bool> {
+        let mut conn = self.db_pool.get()?;
+
+        let is_expired = diesel::select(diesel::dsl::exists(
+            missions::table
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::deleted_at.lt(deleted_before)),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(is_expired)
+    }
+
+    async fn purge(
+        &self,
+        mission_ids: Vec<i32>,
+        deleted_before: NaiveDateTime,
+    ) -> Result<Vec<i32>> {
+        let mut conn = self.db_pool.get()?;
+
+        let purged = conn.transaction::<Vec<i32>, diesel::result::Error, _>(|conn| {
+            // A mission restored since it was picked must keep its rows; the lock keeps a
+            // restore from landing between this check and the deletes.
+            let mission_ids = missions::table
+                .filter(missions::id.eq_any(&mission_ids))
+                .filter(missions::deleted_at.lt(deleted_before))
+                .select(missions::id)
+                .for_update()
+                .load::<i32>(conn)?;
+
+            if mission_ids.is_empty() {
+                return Ok(mission_ids);
+            }
+
+            diesel::delete(crew_memberships::table)
+                .filter(crew_memberships::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+
+            diesel::delete(crew_departures::table)
+                .filter(crew_departures::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+
+            diesel::delete(brawler_ratings::table)
+                .filter(brawler_ratings::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+
+            // Brawlers keep the XP they earned; only the per-mission ledger rows go.
+            diesel::delete(xp_awards::table)
+                .filter(xp_awards::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+
+            diesel::delete(mission_comments::table)
+                .filter(mission_comments::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+
+            diesel::delete(mission_objectives::table)
+                .filter(mission_objectives::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+
+            diesel::delete(mission_attachments::table)
+                .filter(mission_attachments::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+
+            diesel::delete(missions::table)
+                .filter(missions::id.eq_any(&mission_ids))
+                .execute(conn)?;
+
+            Ok(mission_ids)
+        })?;
+
+        Ok(purged)
+    }
 }
@@ -0,0 +1,124 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::mission_objectives::{
+            AddMissionObjectiveEntity, EditMissionObjectiveEntity, MissionObjectiveEntity,
+        },
+        repositories::mission_objectives::MissionObjectiveRepository,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::mission_objectives},
+};
+
+pub struct MissionObjectivePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionObjectivePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionObjectiveRepository for MissionObjectivePostgres {
+    async fn gets(&self, mission_id: i32) -> Result<Vec<MissionObjectiveEntity>> {
+        let mut conn = self.db_pool.get()?;
+
+        let results = mission_objectives::table
+            .filter(mission_objectives::mission_id.eq(mission_id))
+            .order((
+                mission_objectives::position.asc(),
+                mission_objectives::id.asc(),
+            ))
+            .select(MissionObjectiveEntity::as_select())
+            .load::<MissionObjectiveEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn find_by_id(&self, objective_id: i32) -> Result<MissionObjectiveEntity> {
+        let mut conn = self.db_pool.get()?;
+
+        let result = mission_objectives::table
+            .filter(mission_objectives::id.eq(objective_id))
+            .select(MissionObjectiveEntity::as_select())
+            .first::<MissionObjectiveEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn next_position(&self, mission_id: i32) -> Result<i32> {
+        let mut conn = self.db_pool.get()?;
+
+        let last_position = mission_objectives::table
+            .filter(mission_objectives::mission_id.eq(mission_id))
+            .select(diesel::dsl::max(mission_objectives::position))
+            .first::<Option<i32>>(&mut conn)?;
+
+        Ok(last_position.map_or(1, |position| position + 1))
+    }
+
+    async fn add(&self, add_objective_entity: AddMissionObjectiveEntity) -> Result<i32> {
+        let mut conn = self.db_pool.get()?;
+
+        let result = diesel::insert_into(mission_objectives::table)
+            .values(&add_objective_entity)
+            .returning(mission_objectives::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn edit(
+        &self,
+        objective_id: i32,
+        edit_objective_entity: EditMissionObjectiveEntity,
+    ) -> Result<i32> {
+        let mut conn = self.db_pool.get()?;
+
+        let result = diesel::update(mission_objectives::table)
+            .filter(mission_objectives::id.eq(objective_id))
+            .set(&edit_objective_entity)
+            .returning(mission_objectives::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn remove(&self, objective_id: i32) -> Result<()> {
+        let mut conn = self.db_pool.get()?;
+
+        let affected_rows = diesel::delete(mission_objectives::table)
+            .filter(mission_objectives::id.eq(objective_id))
+            .execute(&mut conn)?;
+
+        if affected_rows == 0 {
+            return Err(anyhow::anyhow!("Objective not found"));
+        }
+
+        Ok(())
+    }
+
+    async fn set_completed(&self, objective_id: i32, completed_by: Option<i32>) -> Result<i32> {
+        let mut conn = self.db_pool.get()?;
+
+        let completed_at: Option<NaiveDateTime> =
+            completed_by.map(|_| chrono::Utc::now().naive_utc());
+
+        let result = diesel::update(mission_objectives::table)
+            .filter(mission_objectives::id.eq(objective_id))
+            .set((
+                mission_objectives::completed_at.eq(completed_at),
+                mission_objectives::completed_by.eq(completed_by),
+            ))
+            .returning(mission_objectives::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+}
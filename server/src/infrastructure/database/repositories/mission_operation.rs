@@ -8,7 +8,9 @@ use crate::{
         repositories::mission_operation::MissionOperationRepository,
         value_objects::mission_statuses::MissionStatuses,
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::missions},
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad, repositories::progression, schema::missions,
+    },
 };
 
 pub struct MissionOperationPostgres {
@@ -69,11 +71,29 @@ impl MissionOperationRepository for MissionOperationPostgres {
         Ok(result)
     }
 
-    async fn to_completed(&self, mission_id: i32, _chief_id: i32) -> Result<i32> {
-        let result = self
-            .set_status(mission_id, MissionStatuses::Completed, None)
-            .await?;
-        Ok(result)
+    async fn to_completed(&self, mission_id: i32, _chief_id: i32, xp: i32) -> Result<i32> {
+        let db_pool = Arc::clone(&self.db_pool);
+        let id = tokio::task::spawn_blocking(move || -> Result<i32> {
+            let mut conn = db_pool.get()?;
+
+            let id = conn.transaction::<i32, diesel::result::Error, _>(|conn| {
+                let id = diesel::update(missions::table)
+                    .filter(missions::id.eq(mission_id))
+                    .filter(missions::deleted_at.is_null())
+                    .set(missions::status.eq(MissionStatuses::Completed.to_string()))
+                    .returning(missions::id)
+                    .get_result::<i32>(conn)?;
+
+                progression::award_mission_xp(conn, mission_id, xp)?;
+
+                Ok(id)
+            })?;
+
+            Ok(id)
+        })
+        .await??;
+
+        Ok(id)
     }
 
     async fn to_failed(&self, mission_id: i32, _chief_id: i32) -> Result<i32> {
@@ -42,6 +42,9 @@ impl MissionViewingRepository for MissionViewingPostgres {
                 m.max_crew,
                 m.deadline,
                 m.duration,
+                m.require_objectives,
+                (SELECT (COUNT(*) FILTER (WHERE mo.completed_at IS NOT NULL) * 100 / NULLIF(COUNT(*), 0))::INT4
+                    FROM mission_objectives mo WHERE mo.mission_id = m.id) AS progress,
                 m.created_at,
                 m.updated_at
             FROM missions m
@@ -73,6 +76,9 @@ impl MissionViewingRepository for MissionViewingPostgres {
                 m.max_crew,
                 m.deadline,
                 m.duration,
+                m.require_objectives,
+                (SELECT (COUNT(*) FILTER (WHERE mo.completed_at IS NOT NULL) * 100 / NULLIF(COUNT(*), 0))::INT4
+                    FROM mission_objectives mo WHERE mo.mission_id = m.id) AS progress,
                 m.created_at,
                 m.updated_at
             FROM missions m
@@ -123,29 +129,38 @@ impl MissionViewingRepository for MissionViewingPostgres {
         Ok(count as u32)
     }
 
+    async fn is_crew_member(&self, mission_id: i32, brawler_id: i32) -> Result<bool> {
+        let mut conn = self.db_pool.get()?;
+
+        let is_member = diesel::select(diesel::dsl::exists(
+            crew_memberships::table
+                .filter(crew_memberships::mission_id.eq(mission_id))
+                .filter(crew_memberships::brawler_id.eq(brawler_id)),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(is_member)
+    }
+
     async fn get_mission_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>> {
         let mut conn = self.db_pool.get()?;
 
         let sql = r#"
-            SELECT 
+            SELECT
                 b.display_name,
                 COALESCE(b.avatar_url, '') AS avatar_url,
-                COALESCE(s.success_count, 0) AS mission_success_count,
-                COALESCE(j.joined_count, 0) AS mission_joined_count
+                s.completed_as_chief + s.completed_as_crew AS mission_success_count,
+                s.missions_joined AS mission_joined_count,
+                s.missions_led,
+                s.missions_joined,
+                s.completed_as_chief,
+                s.failed_as_chief,
+                s.completed_as_crew,
+                s.failed_as_crew,
+                s.abandoned
             FROM crew_memberships cm
             INNER JOIN brawlers b ON b.id = cm.brawler_id
-            LEFT JOIN (
-                SELECT cm2.brawler_id, COUNT(*) AS success_count
-                FROM crew_memberships cm2
-                INNER JOIN missions m2 ON m2.id = cm2.mission_id
-                WHERE m2.status = 'SUCCESS'
-                GROUP BY cm2.brawler_id
-            ) s ON s.brawler_id = b.id
-            LEFT JOIN (
-                SELECT cm3.brawler_id, COUNT(*) AS joined_count
-                FROM crew_memberships cm3
-                GROUP BY cm3.brawler_id
-            ) j ON j.brawler_id = b.id
+            INNER JOIN brawler_stats s ON s.brawler_id = b.id
             WHERE cm.mission_id = $1
         "#;
 
@@ -155,4 +170,39 @@ impl MissionViewingRepository for MissionViewingPostgres {
 
         Ok(results)
     }
+
+    async fn gets_deleted(&self, chief_id: i32) -> Result<Vec<MissionModel>> {
+        use diesel::sql_types::Int4;
+
+        let mut conn = self.db_pool.get()?;
+
+        let sql = r#"
+            SELECT
+                m.id,
+                m.name,
+                m.description,
+                m.status,
+                m.chief_id,
+                b.display_name AS chief_display_name,
+                (SELECT COUNT(*) FROM crew_memberships cm WHERE cm.mission_id = m.id) AS crew_count,
+                m.max_crew,
+                m.deadline,
+                m.duration,
+                m.require_objectives,
+                (SELECT (COUNT(*) FILTER (WHERE mo.completed_at IS NOT NULL) * 100 / NULLIF(COUNT(*), 0))::INT4
+                    FROM mission_objectives mo WHERE mo.mission_id = m.id) AS progress,
+                m.created_at,
+                m.updated_at
+            FROM missions m
+            INNER JOIN brawlers b ON b.id = m.chief_id
+            WHERE m.chief_id = $1 AND m.deleted_at IS NOT NULL
+            ORDER BY m.deleted_at DESC
+        "#;
+
+        let results = diesel::sql_query(sql)
+            .bind::<Int4, _>(chief_id)
+            .load::<MissionModel>(&mut conn)?;
+
+        Ok(results)
+    }
 }
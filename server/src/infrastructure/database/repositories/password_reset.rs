@@ -0,0 +1,103 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use crate::{
+    domain::repositories::password_reset::PasswordResetRepository,
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawlers, password_reset_tokens},
+    },
+};
+
+pub struct PasswordResetPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl PasswordResetPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl PasswordResetRepository for PasswordResetPostgres {
+    async fn issue(
+        &self,
+        brawler_id: i32,
+        token_hash: String,
+        expires_at: NaiveDateTime,
+    ) -> Result<()> {
+        let mut conn = self.db_pool.get()?;
+        let now = chrono::Utc::now().naive_utc();
+
+        conn.transaction::<(), diesel::result::Error, _>(|conn| {
+            diesel::update(
+                password_reset_tokens::table
+                    .filter(password_reset_tokens::brawler_id.eq(brawler_id))
+                    .filter(password_reset_tokens::used_at.is_null()),
+            )
+            .set(password_reset_tokens::used_at.eq(now))
+            .execute(conn)?;
+
+            diesel::insert_into(password_reset_tokens::table)
+                .values((
+                    password_reset_tokens::brawler_id.eq(brawler_id),
+                    password_reset_tokens::token_hash.eq(token_hash),
+                    password_reset_tokens::expires_at.eq(expires_at),
+                ))
+                .execute(conn)?;
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_brawler_id(&self, token_hash: String) -> Result<Option<i32>> {
+        let mut conn = self.db_pool.get()?;
+        let now = chrono::Utc::now().naive_utc();
+
+        let result = password_reset_tokens::table
+            .filter(password_reset_tokens::token_hash.eq(token_hash))
+            .filter(password_reset_tokens::used_at.is_null())
+            .filter(password_reset_tokens::expires_at.gt(now))
+            .select(password_reset_tokens::brawler_id)
+            .first::<i32>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn consume(&self, token_hash: String, hashed_password: String) -> Result<bool> {
+        let mut conn = self.db_pool.get()?;
+        let now = chrono::Utc::now().naive_utc();
+
+        let consumed = conn.transaction::<bool, diesel::result::Error, _>(|conn| {
+            let brawler_id = diesel::update(
+                password_reset_tokens::table
+                    .filter(password_reset_tokens::token_hash.eq(token_hash))
+                    .filter(password_reset_tokens::used_at.is_null())
+                    .filter(password_reset_tokens::expires_at.gt(now)),
+            )
+            .set(password_reset_tokens::used_at.eq(now))
+            .returning(password_reset_tokens::brawler_id)
+            .get_result::<i32>(conn)
+            .optional()?;
+
+            let Some(brawler_id) = brawler_id else {
+                return Ok(false);
+            };
+
+            diesel::update(brawlers::table.filter(brawlers::id.eq(brawler_id)))
+                .set(brawlers::password.eq(hashed_password))
+                .execute(conn)?;
+
+            Ok(true)
+        })?;
+
+        Ok(consumed)
+    }
+}
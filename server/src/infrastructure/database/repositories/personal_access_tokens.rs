@@ -0,0 +1,131 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use diesel::{
+    insert_into,
+    prelude::*,
+    result::{DatabaseErrorKind, Error},
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::personal_access_tokens::{
+            AddPersonalAccessTokenEntity, PersonalAccessTokenEntity,
+        },
+        repositories::personal_access_tokens::PersonalAccessTokenRepository,
+        value_objects::personal_access_tokens::TOKEN_NAME_TAKEN,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad, schema::personal_access_tokens,
+    },
+};
+
+/// `last_used_at` is only rewritten once it is this old, so a busy bot doesn't turn every
+/// request into a write.
+const LAST_USED_RESOLUTION_SECONDS: i64 = 60;
+
+pub struct PersonalAccessTokenPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl PersonalAccessTokenPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl PersonalAccessTokenRepository for PersonalAccessTokenPostgres {
+    async fn create(
+        &self,
+        add_personal_access_token_entity: AddPersonalAccessTokenEntity,
+    ) -> Result<PersonalAccessTokenEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(personal_access_tokens::table)
+            .values(add_personal_access_token_entity)
+            .returning(PersonalAccessTokenEntity::as_returning())
+            .get_result::<PersonalAccessTokenEntity>(&mut conn);
+
+        match result {
+            Ok(entity) => Ok(entity),
+            Err(Error::DatabaseError(DatabaseErrorKind::UniqueViolation, info))
+                if info.constraint_name() == Some("uq_personal_access_token_active_name") =>
+            {
+                Err(anyhow::anyhow!(TOKEN_NAME_TAKEN))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn list(&self, brawler_id: i32) -> Result<Vec<PersonalAccessTokenEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = personal_access_tokens::table
+            .filter(personal_access_tokens::brawler_id.eq(brawler_id))
+            .filter(personal_access_tokens::revoked_at.is_null())
+            .order(personal_access_tokens::created_at.desc())
+            .select(PersonalAccessTokenEntity::as_select())
+            .load::<PersonalAccessTokenEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn revoke(&self, brawler_id: i32, token_id: i32) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let revoked = diesel::update(
+            personal_access_tokens::table
+                .filter(personal_access_tokens::id.eq(token_id))
+                .filter(personal_access_tokens::brawler_id.eq(brawler_id))
+                .filter(personal_access_tokens::revoked_at.is_null()),
+        )
+        .set(personal_access_tokens::revoked_at.eq(diesel::dsl::now))
+        .execute(&mut conn)?;
+
+        Ok(revoked > 0)
+    }
+
+    async fn find_active_by_prefix(
+        &self,
+        token_prefix: String,
+    ) -> Result<Option<PersonalAccessTokenEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let now = Utc::now().naive_utc();
+
+        let result = personal_access_tokens::table
+            .filter(personal_access_tokens::token_prefix.eq(token_prefix))
+            .filter(personal_access_tokens::revoked_at.is_null())
+            .filter(
+                personal_access_tokens::expires_at
+                    .is_null()
+                    .or(personal_access_tokens::expires_at.gt(now)),
+            )
+            .select(PersonalAccessTokenEntity::as_select())
+            .first::<PersonalAccessTokenEntity>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn touch(&self, token_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let now = Utc::now().naive_utc();
+        let stale_before = now - Duration::seconds(LAST_USED_RESOLUTION_SECONDS);
+
+        diesel::update(
+            personal_access_tokens::table
+                .filter(personal_access_tokens::id.eq(token_id))
+                .filter(
+                    personal_access_tokens::last_used_at
+                        .is_null()
+                        .or(personal_access_tokens::last_used_at.lt(stale_before)),
+                ),
+        )
+        .set(personal_access_tokens::last_used_at.eq(now))
+        .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
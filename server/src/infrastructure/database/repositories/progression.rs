@@ -0,0 +1,137 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{pg::upsert::on_constraint, prelude::*};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::{
+            brawler_achievements::AddBrawlerAchievementEntity, xp_awards::AddXpAwardEntity,
+        },
+        repositories::progression::ProgressionRepository,
+        value_objects::{brawler_stats::BrawlerStats, progression::Achievement},
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawler_achievements, brawlers, crew_memberships, missions, xp_awards},
+    },
+};
+
+fn participants(conn: &mut PgConnection, mission_id: i32) -> QueryResult<Vec<i32>> {
+    let chief_id = missions::table
+        .filter(missions::id.eq(mission_id))
+        .select(missions::chief_id)
+        .first::<i32>(conn)?;
+
+    let mut participants = crew_memberships::table
+        .filter(crew_memberships::mission_id.eq(mission_id))
+        .select(crew_memberships::brawler_id)
+        .load::<i32>(conn)?;
+
+    if !participants.contains(&chief_id) {
+        participants.insert(0, chief_id);
+    }
+
+    Ok(participants)
+}
+
+/// Awards `amount` XP to everyone on the mission, once per brawler and mission. Takes the
+/// connection so the award commits together with the status change that earns it.
+pub fn award_mission_xp(conn: &mut PgConnection, mission_id: i32, amount: i32) -> QueryResult<()> {
+    let awards = participants(conn, mission_id)?
+        .into_iter()
+        .map(|brawler_id| AddXpAwardEntity {
+            brawler_id,
+            mission_id,
+            amount,
+        })
+        .collect::<Vec<_>>();
+
+    let awarded = diesel::insert_into(xp_awards::table)
+        .values(&awards)
+        .on_conflict(on_constraint("uq_xp_award_brawler_mission"))
+        .do_nothing()
+        .returning(xp_awards::brawler_id)
+        .get_results::<i32>(conn)?;
+
+    diesel::update(brawlers::table.filter(brawlers::id.eq_any(&awarded)))
+        .set(brawlers::xp.eq(brawlers::xp + amount as i64))
+        .execute(conn)?;
+
+    Ok(())
+}
+
+pub struct ProgressionPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl ProgressionPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl ProgressionRepository for ProgressionPostgres {
+    async fn mission_participants(&self, mission_id: i32) -> Result<Vec<i32>> {
+        let mut conn = self.db_pool.get()?;
+
+        Ok(participants(&mut conn, mission_id)?)
+    }
+
+    async fn get_stats(&self, brawler_id: i32) -> Result<BrawlerStats> {
+        use diesel::sql_types::Int4;
+
+        let mut conn = self.db_pool.get()?;
+
+        let sql = r#"
+            SELECT
+                s.missions_led,
+                s.missions_joined,
+                s.completed_as_chief,
+                s.failed_as_chief,
+                s.completed_as_crew,
+                s.failed_as_crew,
+                s.abandoned
+            FROM brawler_stats s
+            WHERE s.brawler_id = $1
+        "#;
+
+        let result = diesel::sql_query(sql)
+            .bind::<Int4, _>(brawler_id)
+            .get_result::<BrawlerStats>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn unlock_achievements(
+        &self,
+        brawler_id: i32,
+        achievements: Vec<Achievement>,
+    ) -> Result<Vec<Achievement>> {
+        if achievements.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = self.db_pool.get()?;
+
+        let values = achievements
+            .iter()
+            .map(|achievement| AddBrawlerAchievementEntity {
+                brawler_id,
+                achievement: achievement.code().to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        let unlocked = diesel::insert_into(brawler_achievements::table)
+            .values(&values)
+            .on_conflict_do_nothing()
+            .returning(brawler_achievements::achievement)
+            .get_results::<String>(&mut conn)?;
+
+        Ok(unlocked
+            .iter()
+            .filter_map(|code| Achievement::from_code(code))
+            .collect())
+    }
+}
@@ -1,5 +1,61 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    audit_log (id) {
+        id -> Int8,
+        actor_id -> Int4,
+        #[max_length = 50]
+        action -> Varchar,
+        #[max_length = 50]
+        target_type -> Varchar,
+        target_id -> Int4,
+        mission_id -> Nullable<Int4>,
+        before -> Nullable<Jsonb>,
+        after -> Nullable<Jsonb>,
+        #[max_length = 36]
+        request_id -> Nullable<Varchar>,
+        #[max_length = 45]
+        ip_address -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    brawler_achievements (brawler_id, achievement) {
+        brawler_id -> Int4,
+        #[max_length = 64]
+        achievement -> Varchar,
+        unlocked_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    brawler_identities (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 255]
+        issuer -> Varchar,
+        #[max_length = 255]
+        subject -> Varchar,
+        #[max_length = 255]
+        email -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        last_login_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    brawler_ratings (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        rater_id -> Int4,
+        ratee_id -> Int4,
+        score -> Int4,
+        comment -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     brawlers (id) {
         id -> Int4,
@@ -15,6 +71,21 @@ diesel::table! {
         avatar_url -> Nullable<Varchar>,
         #[max_length = 255]
         avatar_public_id -> Nullable<Varchar>,
+        avatar_variants -> Nullable<Jsonb>,
+        xp -> Int8,
+        #[max_length = 255]
+        email -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    crew_departures (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 16]
+        reason -> Varchar,
+        departed_at -> Timestamp,
     }
 }
 
@@ -26,6 +97,67 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    login_failures (id) {
+        id -> Int4,
+        #[max_length = 255]
+        username -> Varchar,
+        #[max_length = 64]
+        ip_address -> Nullable<Varchar>,
+        #[max_length = 32]
+        reason -> Varchar,
+        attempted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    login_throttles (throttle_key) {
+        #[max_length = 320]
+        throttle_key -> Varchar,
+        failures -> Int4,
+        locked_until -> Nullable<Timestamp>,
+        last_failure_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_attachments (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        uploaded_by -> Int4,
+        #[max_length = 512]
+        url -> Varchar,
+        #[max_length = 255]
+        public_id -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_comments (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        content -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_objectives (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        position -> Int4,
+        #[max_length = 255]
+        title -> Varchar,
+        completed_at -> Nullable<Timestamp>,
+        completed_by -> Nullable<Int4>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     missions (id) {
         id -> Int4,
@@ -41,11 +173,84 @@ diesel::table! {
         max_crew -> Int4,
         deadline -> Nullable<Timestamp>,
         duration -> Nullable<Int4>,
+        require_objectives -> Bool,
+    }
+}
+
+diesel::table! {
+    password_reset_tokens (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        expires_at -> Timestamp,
+        used_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    personal_access_tokens (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 16]
+        token_prefix -> Varchar,
+        #[max_length = 255]
+        token_hash -> Varchar,
+        scopes -> Array<Text>,
+        created_at -> Timestamp,
+        expires_at -> Nullable<Timestamp>,
+        last_used_at -> Nullable<Timestamp>,
+        revoked_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    xp_awards (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        mission_id -> Int4,
+        amount -> Int4,
+        awarded_at -> Timestamp,
     }
 }
 
+diesel::joinable!(brawler_achievements -> brawlers (brawler_id));
+diesel::joinable!(brawler_identities -> brawlers (brawler_id));
+diesel::joinable!(brawler_ratings -> missions (mission_id));
+diesel::joinable!(crew_departures -> brawlers (brawler_id));
+diesel::joinable!(crew_departures -> missions (mission_id));
 diesel::joinable!(crew_memberships -> brawlers (brawler_id));
 diesel::joinable!(crew_memberships -> missions (mission_id));
+diesel::joinable!(mission_attachments -> brawlers (uploaded_by));
+diesel::joinable!(mission_attachments -> missions (mission_id));
+diesel::joinable!(mission_comments -> brawlers (brawler_id));
+diesel::joinable!(mission_comments -> missions (mission_id));
+diesel::joinable!(mission_objectives -> brawlers (completed_by));
+diesel::joinable!(mission_objectives -> missions (mission_id));
 diesel::joinable!(missions -> brawlers (chief_id));
+diesel::joinable!(password_reset_tokens -> brawlers (brawler_id));
+diesel::joinable!(personal_access_tokens -> brawlers (brawler_id));
+diesel::joinable!(xp_awards -> brawlers (brawler_id));
+diesel::joinable!(xp_awards -> missions (mission_id));
 
-diesel::allow_tables_to_appear_in_same_query!(brawlers, crew_memberships, missions,);
+diesel::allow_tables_to_appear_in_same_query!(
+    audit_log,
+    brawler_achievements,
+    brawler_identities,
+    brawler_ratings,
+    brawlers,
+    crew_departures,
+    crew_memberships,
+    login_failures,
+    login_throttles,
+    mission_attachments,
+    mission_comments,
+    mission_objectives,
+    missions,
+    password_reset_tokens,
+    personal_access_tokens,
+    xp_awards,
+);
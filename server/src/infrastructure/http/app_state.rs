@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::personal_access_tokens::PersonalAccessTokensUseCase,
+    config::config_model::AppConfig,
+    domain::repositories::{
+        image_processor::ImageProcessor, image_storage::ImageStorage, mailer::Mailer,
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::personal_access_tokens::PersonalAccessTokenPostgres,
+        },
+        http::{middleware::rate_limit::RateLimiters, session_cookies::SessionCookies},
+        image_processing::PngImageProcessor,
+        jwt::keys::JwtKeys,
+    },
+};
+
+/// Everything built once at startup and shared by the routers and middleware, so nothing
+/// re-reads `.env` while serving requests.
+#[derive(Clone)]
+pub struct AppState {
+    pub config: Arc<AppConfig>,
+    pub db_pool: Arc<PgPoolSquad>,
+    pub image_storage: Arc<dyn ImageStorage>,
+    pub image_processor: Arc<dyn ImageProcessor>,
+    pub mailer: Arc<dyn Mailer>,
+    pub jwt_keys: Arc<JwtKeys>,
+    pub rate_limiters: RateLimiters,
+    pub session_cookies: SessionCookies,
+    /// Checked by the `authorization` middleware on every request made with a token.
+    pub personal_access_tokens: Arc<PersonalAccessTokensUseCase<PersonalAccessTokenPostgres>>,
+}
+
+impl AppState {
+    pub fn new(
+        config: AppConfig,
+        db_pool: Arc<PgPoolSquad>,
+        image_storage: Arc<dyn ImageStorage>,
+        mailer: Arc<dyn Mailer>,
+        jwt_keys: Arc<JwtKeys>,
+    ) -> Self {
+        let rate_limiters = RateLimiters::new(&config.rate_limit);
+        let session_cookies = SessionCookies::new(&config.session_cookie, config.jwt.ttl);
+        let personal_access_tokens = Arc::new(PersonalAccessTokensUseCase::new(Arc::new(
+            PersonalAccessTokenPostgres::new(Arc::clone(&db_pool)),
+        )));
+
+        Self {
+            config: Arc::new(config),
+            db_pool,
+            image_storage,
+            image_processor: Arc::new(PngImageProcessor),
+            mailer,
+            jwt_keys,
+            rate_limiters,
+            session_cookies,
+            personal_access_tokens,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::convert::Infallible;
+
+use axum::{extract::FromRequestParts, http::request::Parts};
+
+use crate::{
+    domain::value_objects::audit_log::AuditContext,
+    infrastructure::http::extractors::client_ip::ClientIp,
+};
+
+/// Set on every request by the request-id layer in `http_serv`.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// The request id and client address an audit entry is stamped with.
+pub struct Audit(pub AuditContext);
+
+impl<S> FromRequestParts<S> for Audit
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let request_id = parts
+            .headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let ClientIp(ip_address) = ClientIp::from_request_parts(parts, state).await?;
+
+        Ok(Self(AuditContext {
+            request_id,
+            ip_address,
+        }))
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    convert::Infallible,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+
+use axum::{
+    body::Body,
+    extract::{ConnectInfo, FromRequestParts, State},
+    http::{HeaderMap, Request, request::Parts},
+    middleware::Next,
+    response::Response,
+};
+use ipnet::IpNet;
+
+/// The address the request came from: the connection's peer or, when that peer is one of
+/// `SERVER_TRUSTED_PROXIES`, the client the proxy forwarded for. `None` when the server runs
+/// without connect info (e.g. in-process tests).
+#[derive(Debug, Clone)]
+pub struct ClientIp(pub Option<String>);
+
+impl<S> FromRequestParts<S> for ClientIp
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .extensions
+            .get::<ClientIp>()
+            .cloned()
+            .unwrap_or(ClientIp(None)))
+    }
+}
+
+/// Resolves `ClientIp` once per request, before the rate limiters, handlers and audit log
+/// read it.
+pub async fn resolve_client_ip(
+    State(trusted_proxies): State<Arc<Vec<IpNet>>>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Response {
+    let ip = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| client_address(addr.ip(), req.headers(), &trusted_proxies));
+
+    req.extensions_mut()
+        .insert(ClientIp(ip.map(|ip| ip.to_string())));
+    next.run(req).await
+}
+
+/// Walks the forwarded chain from the nearest hop back and stops at the first address that is
+/// not a trusted proxy; anything further left could have been written by the client.
+fn client_address(peer: IpAddr, headers: &HeaderMap, trusted_proxies: &[IpNet]) -> IpAddr {
+    let is_trusted = |ip: &IpAddr| trusted_proxies.iter().any(|network| network.contains(ip));
+
+    let mut client = peer;
+    if !is_trusted(&client) {
+        return client;
+    }
+
+    for hop in forwarded_chain(headers).into_iter().rev() {
+        // `unknown` or an obfuscated identifier: nothing past it can be attributed.
+        let Some(hop) = hop else {
+            break;
+        };
+        client = hop;
+        if !is_trusted(&client) {
+            break;
+        }
+    }
+
+    client
+}
+
+/// Hop addresses, client first. `Forwarded` (RFC 7239) wins over `X-Forwarded-For`.
+fn forwarded_chain(headers: &HeaderMap) -> Vec<Option<IpAddr>> {
+    let forwarded: Vec<&str> = headers
+        .get_all("forwarded")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect();
+    if !forwarded.is_empty() {
+        return forwarded
+            .iter()
+            .flat_map(|value| value.split(','))
+            .map(|element| {
+                element
+                    .split(';')
+                    .filter_map(|pair| pair.trim().split_once('='))
+                    .find(|(name, _)| name.eq_ignore_ascii_case("for"))
+                    .and_then(|(_, node)| parse_node(node))
+            })
+            .collect();
+    }
+
+    headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(parse_node)
+        .collect()
+}
+
+/// Accepts `1.2.3.4`, `1.2.3.4:80`, `2001:db8::1` and `"[2001:db8::1]:80"`.
+fn parse_node(node: &str) -> Option<IpAddr> {
+    let node = node.trim().trim_matches('"');
+    node.parse::<IpAddr>()
+        .ok()
+        .or_else(|| node.parse::<SocketAddr>().ok().map(|addr| addr.ip()))
+        .or_else(|| {
+            node.strip_prefix('[')
+                .and_then(|rest| rest.split_once(']'))
+                .and_then(|(ip, _)| ip.parse().ok())
+        })
+}
@@ -0,0 +1,98 @@
+use axum::{
+    Json,
+    extract::{FromRequest, Multipart, Request},
+    http::{StatusCode, header::CONTENT_TYPE},
+    response::{IntoResponse, Response},
+};
+
+use crate::domain::value_objects::{
+    base64_image::{Base64Image, MAX_IMAGE_BYTES, sniff_mime_type},
+    uploaded_image::UploadBase64Image,
+};
+
+const FILE_FIELD: &str = "file";
+
+/// Enough leading bytes for `infer` to recognise every supported image type.
+const SNIFF_BYTES: usize = 64;
+
+/// An image sent either as `multipart/form-data` (a `file` field) or as the
+/// `UploadBase64Image` JSON body. Multipart uploads are read chunk by chunk, so an
+/// oversized or non-image file is rejected without buffering the whole body.
+pub struct ImageUpload(pub Base64Image);
+
+impl<S> FromRequest<S> for ImageUpload
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let is_multipart = req
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("multipart/form-data"));
+
+        if is_multipart {
+            let multipart = Multipart::from_request(req, state)
+                .await
+                .map_err(IntoResponse::into_response)?;
+            return from_multipart(multipart).await.map(Self);
+        }
+
+        let Json(upload_image) = Json::<UploadBase64Image>::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        Base64Image::new(upload_image.base64_string)
+            .map(Self)
+            .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()).into_response())
+    }
+}
+
+async fn from_multipart(mut multipart: Multipart) -> Result<Base64Image, Response> {
+    while let Some(mut field) = multipart
+        .next_field()
+        .await
+        .map_err(IntoResponse::into_response)?
+    {
+        if field.name() != Some(FILE_FIELD) {
+            continue;
+        }
+
+        let mut bytes: Vec<u8> = Vec::new();
+        let mut sniffed = false;
+
+        while let Some(chunk) = field.chunk().await.map_err(IntoResponse::into_response)? {
+            if bytes.len() + chunk.len() > MAX_IMAGE_BYTES {
+                return Err((
+                    StatusCode::PAYLOAD_TOO_LARGE,
+                    format!("Image must not exceed {} bytes", MAX_IMAGE_BYTES),
+                )
+                    .into_response());
+            }
+            bytes.extend_from_slice(&chunk);
+
+            if !sniffed && bytes.len() >= SNIFF_BYTES {
+                sniff_mime_type(&bytes).map_err(unsupported_media_type)?;
+                sniffed = true;
+            }
+        }
+
+        if bytes.is_empty() {
+            return Err((StatusCode::BAD_REQUEST, "Uploaded file is empty").into_response());
+        }
+
+        return Base64Image::from_bytes(&bytes).map_err(unsupported_media_type);
+    }
+
+    Err((
+        StatusCode::BAD_REQUEST,
+        format!("Missing multipart field '{}'", FILE_FIELD),
+    )
+        .into_response())
+}
+
+fn unsupported_media_type(e: anyhow::Error) -> Response {
+    (StatusCode::UNSUPPORTED_MEDIA_TYPE, e.to_string()).into_response()
+}
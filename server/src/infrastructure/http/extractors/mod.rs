@@ -0,0 +1,4 @@
+pub mod audit_context;
+pub mod client_ip;
+pub mod image_upload;
+pub mod validated_json;
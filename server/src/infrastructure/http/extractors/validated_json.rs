@@ -0,0 +1,50 @@
+use axum::{
+    Json,
+    extract::{FromRequest, Request},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::domain::value_objects::validation::{FieldError, Validate, ValidationErrors};
+
+#[derive(Debug, Serialize)]
+pub struct ValidationErrorResponse {
+    pub message: &'static str,
+    pub errors: Vec<FieldError>,
+}
+
+/// `Json<T>` that also runs `T`'s validation rules. A body that parses but breaks a rule is
+/// rejected with `422 Unprocessable Entity` and every field error, before the handler runs.
+pub struct ValidatedJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        value.validate().map_err(IntoResponse::into_response)?;
+
+        Ok(Self(value))
+    }
+}
+
+impl IntoResponse for ValidationErrors {
+    fn into_response(self) -> Response {
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ValidationErrorResponse {
+                message: "Validation failed",
+                errors: self.errors,
+            }),
+        )
+            .into_response()
+    }
+}
@@ -2,8 +2,9 @@ use anyhow::{Ok, Result};
 use axum::http::StatusCode;
 use axum::{
     Router,
+    body::Body,
     http::{
-        Method,
+        HeaderName, HeaderValue, Method, Request,
         header::{AUTHORIZATION, CONTENT_TYPE},
     },
     routing::get,
@@ -11,8 +12,9 @@ use axum::{
 use std::{net::SocketAddr, sync::Arc, time::Duration};
 use tokio::net::TcpListener;
 use tower_http::{
-    cors::{Any, CorsLayer},
+    cors::{AllowOrigin, Any, CorsLayer},
     limit::RequestBodyLimitLayer,
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
     services::{ServeDir, ServeFile},
     timeout::TimeoutLayer,
     trace::TraceLayer,
@@ -20,10 +22,16 @@ use tower_http::{
 use tracing::info;
 
 use crate::{
-    config::config_model::DotEnvyConfig,
-    infrastructure::{
-        database::postgresql_connection::PgPoolSquad,
-        http::routers::{self, default_router},
+    config::{
+        config_model::{CorsEnv, ImageStorageBackend, ImageStorageEnv},
+        stage::Stage,
+    },
+    infrastructure::http::{
+        app_state::AppState,
+        extractors::{audit_context::REQUEST_ID_HEADER, client_ip::resolve_client_ip},
+        middleware::error_detail::hide_internal_errors,
+        routers::{self, default_router},
+        session_cookies::CSRF_HEADER,
     },
 };
 
@@ -35,40 +43,96 @@ fn static_serve() -> Router {
     Router::new().fallback_service(service)
 }
 
-fn api_serve(db_pool: Arc<PgPoolSquad>) -> Router {
+fn uploads_serve(image_storage_env: &ImageStorageEnv) -> Router {
+    match image_storage_env.backend {
+        ImageStorageBackend::Local => Router::new().nest_service(
+            &image_storage_env.local_url,
+            ServeDir::new(&image_storage_env.local_dir),
+        ),
+        ImageStorageBackend::Cloudinary => Router::new(),
+    }
+}
+
+fn cors_layer(cors_env: &CorsEnv) -> Result<CorsLayer> {
+    // Empty only outside Prod; the loader rejects a Prod config without origins.
+    let allow_origin = if cors_env.allowed_origins.is_empty() {
+        AllowOrigin::from(Any)
+    } else {
+        let origins = cors_env
+            .allowed_origins
+            .iter()
+            .map(|origin| origin.parse())
+            .collect::<Result<Vec<HeaderValue>, _>>()?;
+        AllowOrigin::list(origins)
+    };
+
+    Ok(CorsLayer::new()
+        .allow_methods([
+            Method::GET,
+            Method::POST,
+            Method::PUT,
+            Method::PATCH,
+            Method::DELETE,
+            Method::OPTIONS,
+        ])
+        .allow_origin(allow_origin)
+        .allow_headers([
+            AUTHORIZATION,
+            CONTENT_TYPE,
+            HeaderName::from_static(CSRF_HEADER),
+        ])
+        // Lets a frontend on another origin pick up the CSRF token issued at login, and the
+        // request id to quote when reporting a problem.
+        .expose_headers([
+            HeaderName::from_static(CSRF_HEADER),
+            HeaderName::from_static(REQUEST_ID_HEADER),
+        ])
+        .allow_credentials(cors_env.allow_credentials))
+}
+
+fn api_serve(state: &AppState) -> Router {
     Router::new()
-        .nest("/brawlers", routers::brawlers::routes(Arc::clone(&db_pool)))
-        .nest(
-            "/authentication",
-            routers::authentication::routes(Arc::clone(&db_pool)),
-        )
+        .nest("/brawlers", routers::brawlers::routes(state))
+        .nest("/authentication", routers::authentication::routes(state))
         .nest(
             "/mission-management",
-            routers::mission_management::routes(Arc::clone(&db_pool)),
+            routers::mission_management::routes(state),
         )
+        .nest("/crew-operation", routers::crew_operation::routes(state))
         .nest(
-            "/crew-operation",
-            routers::crew_operation::routes(Arc::clone(&db_pool)),
+            "/mission-operation",
+            routers::mission_operation::routes(state),
         )
+        .nest("/mission-viewing", routers::mission_viewing::routes(state))
         .nest(
-            "/mission-operation",
-            routers::mission_operation::routes(Arc::clone(&db_pool)),
+            "/mission-objectives",
+            routers::mission_objectives::routes(state),
         )
         .nest(
-            "/mission-viewing",
-            routers::mission_viewing::routes(Arc::clone(&db_pool)),
+            "/mission-attachments",
+            routers::mission_attachments::routes(state),
         )
+        .nest("/dashboard", routers::dashboard::routes(state))
         .nest(
-            "/dashboard",
-            routers::dashboard::routes(Arc::clone(&db_pool)),
+            "/personal-access-tokens",
+            routers::personal_access_tokens::routes(state),
         )
+        .nest("/brawler-ratings", routers::brawler_ratings::routes(state))
+        .nest("/leaderboard", routers::leaderboard::routes(state))
+        .nest("/admin", routers::admin::routes(state))
         .fallback(|| async { (StatusCode::NOT_FOUND, "API not found") })
+        // Wraps every router, so it runs before `authorization` and counts per client IP.
+        .layer(state.rate_limiters.default.clone())
 }
 
-pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
-    let app = Router::new()
+pub async fn start(state: AppState) -> Result<()> {
+    let config = Arc::clone(&state.config);
+
+    let mut app = Router::new()
+        .merge(uploads_serve(&config.image_storage))
         .merge(static_serve())
-        .nest("/api", api_serve(db_pool))
+        .nest("/api", api_serve(&state))
+        .nest("/.well-known", routers::well_known::routes(&state))
         .route("/error/{status_code_u16}", get(default_router::error))
         .layer(TimeoutLayer::with_status_code(
             StatusCode::GATEWAY_TIMEOUT,
@@ -77,28 +141,45 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
         .layer(RequestBodyLimitLayer::new(
             (config.server.body_limit * 1024 * 1024).try_into()?,
         ))
-        .layer(
-            CorsLayer::new()
-                .allow_methods([
-                    Method::GET,
-                    Method::POST,
-                    Method::PUT,
-                    Method::PATCH,
-                    Method::DELETE,
-                    Method::OPTIONS,
-                ])
-                .allow_origin(Any)
-                .allow_headers([AUTHORIZATION, CONTENT_TYPE]),
-        )
-        .layer(TraceLayer::new_for_http());
+        // Before every rate limiter, so they key on the forwarded client, not the proxy.
+        .layer(axum::middleware::from_fn_with_state(
+            Arc::new(config.server.trusted_proxies.clone()),
+            resolve_client_ip,
+        ));
+
+    if config.stage == Stage::Production {
+        app = app.layer(axum::middleware::from_fn(hide_internal_errors));
+    }
+
+    let app = app
+        .layer(cors_layer(&config.cors)?)
+        .layer(PropagateRequestIdLayer::new(HeaderName::from_static(
+            REQUEST_ID_HEADER,
+        )))
+        .layer(TraceLayer::new_for_http())
+        // Outermost, so the trace span and the audit log see the same id.
+        .layer(SetRequestIdLayer::new(
+            HeaderName::from_static(REQUEST_ID_HEADER),
+            MakeRequestUuid,
+        ))
+        // The layer above keeps an id that is already set; audit entries must carry ours.
+        .layer(axum::middleware::map_request(
+            |mut req: Request<Body>| async move {
+                req.headers_mut().remove(REQUEST_ID_HEADER);
+                req
+            },
+        ));
 
     let addr = SocketAddr::from(([0, 0, 0, 0], config.server.port));
     let listener = TcpListener::bind(addr).await?;
 
     info!("Server start on port {}", config.server.port);
-    axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
-        .await?;
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await?;
 
     Ok(())
 }
@@ -1,41 +1,203 @@
-use crate::config::config_loader::get_user_secret;
-use crate::infrastructure;
+use crate::{
+    domain::value_objects::personal_access_tokens::{PERSONAL_ACCESS_TOKEN_PREFIX, TokenScope},
+    infrastructure::{
+        self,
+        http::{
+            app_state::AppState,
+            session_cookies::{CSRF_COOKIE, CSRF_HEADER, SESSION_COOKIE},
+        },
+        jwt::keys::JwtKeys,
+    },
+};
 use axum::{
+    Extension,
     body::Body,
-    http::{Request, StatusCode, header},
+    extract::{OriginalUri, State},
+    http::{Method, Request, StatusCode, header},
     middleware::Next,
     response::Response,
 };
 
-pub async fn authorization(mut req: Request<Body>, next: Next) -> Result<Response, StatusCode> {
-    let token = req
-        .headers()
+/// Accepts a brawler's JWT (bearer or session cookie) or a personal access token whose scopes
+/// cover the request, and hands the brawler on as `Extension<i32>`.
+pub async fn authorization(
+    State(state): State<AppState>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let credential = read_credential(&state, &req)?;
+    let brawler_id = authenticate(&state, credential).await?;
+
+    req.extensions_mut().insert(brawler_id);
+
+    Ok(next.run(req).await)
+}
+
+/// Like `authorization`, but lets anonymous requests through as `Extension<Option<i32>>`.
+pub async fn optional_authorization(
+    State(state): State<AppState>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Response {
+    let brawler_id = match read_credential(&state, &req) {
+        Ok(credential) => authenticate(&state, credential).await.ok(),
+        Err(_) => None,
+    };
+
+    req.extensions_mut().insert(brawler_id);
+
+    next.run(req).await
+}
+
+/// Runs inside `authorization` and only lets the brawlers listed in `ADMIN_BRAWLER_IDS` through.
+pub async fn admin_only(
+    State(state): State<AppState>,
+    Extension(brawler_id): Extension<i32>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    if !state.config.admin.brawler_ids.contains(&brawler_id) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    Ok(next.run(req).await)
+}
+
+enum Credential {
+    /// A verified JWT.
+    Brawler(i32),
+    /// Still to be checked against the database.
+    PersonalAccessToken {
+        token: String,
+        required_scope: Option<TokenScope>,
+    },
+}
+
+/// Everything that only needs the request itself, done before any `.await` so the request
+/// isn't borrowed across one.
+fn read_credential(state: &AppState, req: &Request<Body>) -> Result<Credential, StatusCode> {
+    let (token, from_cookie) = extract_token(req).ok_or(StatusCode::UNAUTHORIZED)?;
+
+    if !from_cookie && token.starts_with(PERSONAL_ACCESS_TOKEN_PREFIX) {
+        // Nested routers see their own part of the path; scopes are defined on the full one.
+        let path = req
+            .extensions()
+            .get::<OriginalUri>()
+            .map(|original_uri| original_uri.path())
+            .unwrap_or_else(|| req.uri().path());
+
+        return Ok(Credential::PersonalAccessToken {
+            required_scope: required_scope(req.method(), path),
+            token,
+        });
+    }
+
+    let brawler_id = verify_brawler_id(&state.jwt_keys, &token).ok_or(StatusCode::UNAUTHORIZED)?;
+
+    // Browsers attach cookies to cross-site requests too, so those must prove they can read
+    // the CSRF cookie; a bearer token is never sent implicitly and needs no such proof.
+    if from_cookie && !csrf_satisfied(req) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    Ok(Credential::Brawler(brawler_id))
+}
+
+async fn authenticate(state: &AppState, credential: Credential) -> Result<i32, StatusCode> {
+    let (token, required_scope) = match credential {
+        Credential::Brawler(brawler_id) => return Ok(brawler_id),
+        Credential::PersonalAccessToken {
+            token,
+            required_scope,
+        } => (token, required_scope),
+    };
+
+    let grant = match state.personal_access_tokens.authenticate(&token).await {
+        Ok(Some(grant)) => grant,
+        Ok(None) => return Err(StatusCode::UNAUTHORIZED),
+        Err(e) => {
+            tracing::error!("Failed to check personal access token: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    match required_scope {
+        Some(scope) if grant.scopes.contains(&scope) => Ok(grant.brawler_id),
+        _ => Err(StatusCode::FORBIDDEN),
+    }
+}
+
+/// The scope a personal access token needs for a request. `None` keeps a route to signed-in
+/// brawlers only, so a token can't change the password, sign in elsewhere, mint more tokens or
+/// reach the admin endpoints.
+fn required_scope(method: &Method, path: &str) -> Option<TokenScope> {
+    let area = path.strip_prefix("/api/")?.split('/').next()?;
+
+    if matches!(area, "authentication" | "personal-access-tokens" | "admin")
+        || path.starts_with("/api/brawlers/password")
+    {
+        return None;
+    }
+
+    if method.is_safe() {
+        return Some(TokenScope::Read);
+    }
+
+    match area {
+        "mission-management"
+        | "mission-operation"
+        | "mission-objectives"
+        | "mission-attachments"
+        | "mission-viewing"
+        | "brawler-ratings" => Some(TokenScope::MissionsWrite),
+        "crew-operation" => Some(TokenScope::CrewWrite),
+        "brawlers" => Some(TokenScope::ProfileWrite),
+        _ => None,
+    }
+}
+
+/// The token, and whether it came from the session cookie rather than the `Authorization` header.
+fn extract_token(req: &Request<Body>) -> Option<(String, bool)> {
+    req.headers()
         .get(header::AUTHORIZATION)
         .and_then(|auth_header| auth_header.to_str().ok())
         .and_then(|auth_value| auth_value.strip_prefix("Bearer "))
-        .map(|token| token.to_string())
-        .or_else(|| {
-            req.headers()
-                .get(header::COOKIE)
-                .and_then(|cookie_header| cookie_header.to_str().ok())
-                .and_then(|cookie_str| get_cookie_value(cookie_str, "token"))
-        })
-        .ok_or(StatusCode::UNAUTHORIZED)?;
+        .map(|token| (token.to_string(), false))
+        .or_else(|| cookie_value(req, SESSION_COOKIE).map(|token| (token, true)))
+}
 
+/// Double-submit check: safe methods pass, anything else must echo the CSRF cookie in
+/// `X-CSRF-Token`.
+fn csrf_satisfied(req: &Request<Body>) -> bool {
+    if req.method().is_safe() {
+        return true;
+    }
 
-    let secret = get_user_secret().map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let header_token = req
+        .headers()
+        .get(CSRF_HEADER)
+        .and_then(|value| value.to_str().ok());
 
-    let claims =
-        infrastructure::jwt::verify_token(&secret, &token).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    match (cookie_value(req, CSRF_COOKIE), header_token) {
+        (Some(cookie_token), Some(header_token)) => {
+            !cookie_token.is_empty() && cookie_token == header_token
+        }
+        _ => false,
+    }
+}
 
-    let brawler_id = claims
-        .sub
-        .parse::<i32>()
-        .map_err(|_| StatusCode::UNAUTHORIZED)?;
+fn verify_brawler_id(jwt_keys: &JwtKeys, token: &str) -> Option<i32> {
+    let claims = infrastructure::jwt::verify_token(jwt_keys, token).ok()?;
 
-    req.extensions_mut().insert(brawler_id);
+    claims.sub.parse::<i32>().ok()
+}
 
-    Ok(next.run(req).await)
+fn cookie_value(req: &Request<Body>, key: &str) -> Option<String> {
+    req.headers()
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|cookie_header| cookie_header.to_str().ok())
+        .find_map(|cookie_str| get_cookie_value(cookie_str, key))
 }
 
 fn get_cookie_value(cookie_header: &str, key: &str) -> Option<String> {
@@ -0,0 +1,44 @@
+use axum::{
+    body::{self, Body},
+    http::{HeaderValue, Request, header},
+    middleware::Next,
+    response::Response,
+};
+use tracing::error;
+
+/// Bodies above this are logged without their text.
+const MAX_LOGGED_BODY: usize = 64 * 1024;
+
+/// Prod only: handlers answer 5xx with `e.to_string()`, which can carry SQL errors, file
+/// paths or upstream responses. The text goes to the log and the client gets the reason phrase.
+pub async fn hide_internal_errors(req: Request<Body>, next: Next) -> Response {
+    let method = req.method().clone();
+    let path = req.uri().path().to_string();
+
+    let response = next.run(req).await;
+    let status = response.status();
+    if !status.is_server_error() {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    match body::to_bytes(body, MAX_LOGGED_BODY).await {
+        Ok(detail) if !detail.is_empty() => error!(
+            "{} {} failed with {}: {}",
+            method,
+            path,
+            status.as_u16(),
+            String::from_utf8_lossy(&detail)
+        ),
+        _ => error!("{} {} failed with {}", method, path, status.as_u16()),
+    }
+
+    parts.headers.remove(header::CONTENT_LENGTH);
+    parts.headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("text/plain; charset=utf-8"),
+    );
+    let message = status.canonical_reason().unwrap_or("Internal Server Error");
+
+    Response::from_parts(parts, Body::from(message))
+}
@@ -1 +1,3 @@
-pub mod auth;
\ No newline at end of file
+pub mod auth;
+pub mod error_detail;
+pub mod rate_limit;
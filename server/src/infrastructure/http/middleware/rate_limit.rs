@@ -0,0 +1,188 @@
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode, header::RETRY_AFTER},
+    response::{IntoResponse, Response},
+};
+use tower::{Layer, Service};
+use tracing::warn;
+
+use crate::{
+    config::config_model::{RateLimit, RateLimitEnv},
+    infrastructure::http::extractors::client_ip::ClientIp,
+};
+
+/// Idle buckets are dropped once a limiter tracks more keys than this.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// Token buckets for one route group: `requests` tokens that refill evenly over `per_seconds`.
+pub struct RateLimiter {
+    group: &'static str,
+    limit: RateLimit,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(group: &'static str, limit: RateLimit) -> Self {
+        Self {
+            group,
+            limit,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn refill_rate(&self) -> f64 {
+        self.limit.requests as f64 / self.limit.per_seconds.max(1) as f64
+    }
+
+    /// Takes a token for `key`, or returns how many seconds until one is available.
+    fn try_acquire(&self, key: &str) -> Result<(), u64> {
+        let capacity = self.limit.requests as f64;
+        let refill_rate = self.refill_rate();
+        let now = Instant::now();
+
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+
+        if buckets.len() > PRUNE_THRESHOLD {
+            buckets.retain(|_, bucket| {
+                bucket.tokens + now.duration_since(bucket.refilled_at).as_secs_f64() * refill_rate
+                    < capacity
+            });
+        }
+
+        let bucket = buckets.entry(key.to_string()).or_insert(Bucket {
+            tokens: capacity,
+            refilled_at: now,
+        });
+
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * refill_rate).min(capacity);
+        bucket.refilled_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(((1.0 - bucket.tokens) / refill_rate).ceil().max(1.0) as u64)
+        }
+    }
+}
+
+/// Requests are counted per brawler when the `authorization` middleware already ran
+/// (an `Extension<i32>`, or `Extension<Option<i32>>` from `optional_authorization`),
+/// otherwise per client IP.
+fn rate_limit_key(req: &Request<Body>) -> String {
+    let brawler_id = req
+        .extensions()
+        .get::<i32>()
+        .copied()
+        .or_else(|| req.extensions().get::<Option<i32>>().copied().flatten());
+
+    if let Some(brawler_id) = brawler_id {
+        return format!("brawler:{}", brawler_id);
+    }
+
+    match req.extensions().get::<ClientIp>() {
+        Some(ClientIp(Some(ip))) => format!("ip:{}", ip),
+        _ => "anonymous".to_string(),
+    }
+}
+
+/// Rejects requests over the group's limit with `429 Too Many Requests` and `Retry-After`.
+/// A group configured as `off` lets everything through.
+#[derive(Clone)]
+pub struct RateLimitLayer {
+    limiter: Option<Arc<RateLimiter>>,
+}
+
+impl RateLimitLayer {
+    pub fn new(group: &'static str, limit: Option<RateLimit>) -> Self {
+        Self {
+            limiter: limit.map(|limit| Arc::new(RateLimiter::new(group, limit))),
+        }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitService {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RateLimitService<S> {
+    inner: S,
+    limiter: Option<Arc<RateLimiter>>,
+}
+
+impl<S> Service<Request<Body>> for RateLimitService<S>
+where
+    S: Service<Request<Body>, Response = Response, Error = Infallible> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        if let Some(limiter) = &self.limiter {
+            let key = rate_limit_key(&req);
+            if let Err(retry_after) = limiter.try_acquire(&key) {
+                warn!("Rate limit '{}' exceeded by {}", limiter.group, key);
+                let response = (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    [(RETRY_AFTER, retry_after.to_string())],
+                    "Too many requests, slow down!",
+                )
+                    .into_response();
+                return Box::pin(async move { Ok(response) });
+            }
+        }
+
+        Box::pin(self.inner.call(req))
+    }
+}
+
+/// One shared limiter per route group, so e.g. `register` and `login` draw from the same
+/// `auth` buckets.
+#[derive(Clone)]
+pub struct RateLimiters {
+    pub default: RateLimitLayer,
+    pub auth: RateLimitLayer,
+    pub crew: RateLimitLayer,
+    pub uploads: RateLimitLayer,
+}
+
+impl RateLimiters {
+    pub fn new(rate_limit_env: &RateLimitEnv) -> Self {
+        Self {
+            default: RateLimitLayer::new("default", rate_limit_env.default.clone()),
+            auth: RateLimitLayer::new("auth", rate_limit_env.auth.clone()),
+            crew: RateLimitLayer::new("crew", rate_limit_env.crew.clone()),
+            uploads: RateLimitLayer::new("uploads", rate_limit_env.uploads.clone()),
+        }
+    }
+}
@@ -1,4 +1,6 @@
+pub mod app_state;
+pub mod extractors;
 pub mod http_serv;
-pub mod routers;
 pub mod middleware;
-
+pub mod routers;
+pub mod session_cookies;
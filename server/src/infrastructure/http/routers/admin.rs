@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::get,
+};
+
+use crate::{
+    application::use_cases::audit_log::AuditLogUseCase,
+    domain::{
+        repositories::audit_log::AuditLogRepository, value_objects::audit_log::AuditLogQuery,
+    },
+    infrastructure::{
+        database::repositories::audit_log::AuditLogPostgres,
+        http::{
+            app_state::AppState,
+            middleware::auth::{admin_only, authorization},
+        },
+    },
+};
+
+pub async fn audit_log<T>(
+    State(use_case): State<Arc<AuditLogUseCase<T>>>,
+    Query(query): Query<AuditLogQuery>,
+) -> impl IntoResponse
+where
+    T: AuditLogRepository + Send + Sync,
+{
+    match use_case.search(query).await {
+        Ok(page) => (StatusCode::OK, Json(page)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(state: &AppState) -> Router {
+    let audit_log_repository = AuditLogPostgres::new(Arc::clone(&state.db_pool));
+    let use_case = AuditLogUseCase::new(Arc::new(audit_log_repository));
+
+    Router::new()
+        .route("/audit-log", get(audit_log))
+        // Layers run bottom-up, so `authorization` has set the brawler id before `admin_only`.
+        .route_layer(middleware::from_fn_with_state(state.clone(), admin_only))
+        .route_layer(middleware::from_fn_with_state(state.clone(), authorization))
+        .with_state(Arc::new(use_case))
+}
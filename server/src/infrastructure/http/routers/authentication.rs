@@ -1,29 +1,73 @@
 use std::sync::Arc;
 
-use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::post};
+use axum::{
+    Extension, Json, Router,
+    extract::State,
+    http::{StatusCode, header::RETRY_AFTER},
+    response::IntoResponse,
+    routing::post,
+};
+
+use axum_extra::extract::cookie::CookieJar;
 
 use crate::{
-    application::use_cases::authentication::AuthenticationUseCase,
-    domain::repositories::brawlers::BrawlerRepository,
+    application::use_cases::{
+        authentication::AuthenticationUseCase, password_reset::PasswordResetUseCase,
+    },
+    domain::{
+        repositories::{
+            brawlers::BrawlerRepository, login_attempts::LoginAttemptRepository,
+            password_reset::PasswordResetRepository,
+        },
+        value_objects::{
+            credentials::{CredentialsError, PasswordResetConfirmModel, PasswordResetRequestModel},
+            login_throttle::{INVALID_CREDENTIALS, LoginLocked},
+            validation::ValidationErrors,
+        },
+    },
     infrastructure::{
-        database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres},
+        database::repositories::{
+            brawlers::BrawlerPostgres, login_attempts::LoginAttemptPostgres,
+            password_reset::PasswordResetPostgres,
+        },
+        http::{
+            app_state::AppState, extractors::client_ip::ClientIp, routers::oidc_login,
+            session_cookies::SessionCookies,
+        },
         jwt::authentication_model::LoginModel,
     },
 };
 
-pub async fn login<T>(
-    State(user_case): State<Arc<AuthenticationUseCase<T>>>,
+pub async fn login<T1, T2>(
+    State(user_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
+    ClientIp(ip_address): ClientIp,
+    Extension(session_cookies): Extension<SessionCookies>,
+    jar: CookieJar,
     Json(model): Json<LoginModel>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: LoginAttemptRepository + Send + Sync,
 {
-    match user_case.login(model).await {
-        Ok(passport) => (StatusCode::OK, Json(passport)).into_response(),
+    match user_case.login(model, ip_address).await {
+        Ok(passport) => (
+            StatusCode::OK,
+            session_cookies.issue(jar, &passport),
+            Json(passport),
+        )
+            .into_response(),
         Err(e) => {
+            if let Some(locked) = e.downcast_ref::<LoginLocked>() {
+                return (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    [(RETRY_AFTER, locked.retry_after.to_string())],
+                    locked.to_string(),
+                )
+                    .into_response();
+            }
+
             let error_message = e.to_string();
-            let error_lower = error_message.to_lowercase();
-            if error_lower.contains("invalid password") || error_lower.contains("not found") {
+            if error_message == INVALID_CREDENTIALS {
                 (StatusCode::UNAUTHORIZED, error_message).into_response()
             } else {
                 (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
@@ -32,11 +76,94 @@ where
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let repository = BrawlerPostgres::new(db_pool.clone());
-    let use_case = AuthenticationUseCase::new(Arc::new(repository));
+/// Ends a cookie session; bearer-token clients just drop their token.
+pub async fn logout(
+    Extension(session_cookies): Extension<SessionCookies>,
+    jar: CookieJar,
+) -> impl IntoResponse {
+    (StatusCode::NO_CONTENT, session_cookies.clear(jar)).into_response()
+}
+
+pub async fn request_password_reset<T1, T2>(
+    State(use_case): State<Arc<PasswordResetUseCase<T1, T2>>>,
+    Json(model): Json<PasswordResetRequestModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: PasswordResetRepository + Send + Sync,
+{
+    // Always 202, whether or not the username exists, so accounts can't be enumerated.
+    if let Err(e) = use_case.request(model.username).await {
+        tracing::error!("Failed to issue password reset: {}", e);
+    }
+
+    StatusCode::ACCEPTED.into_response()
+}
+
+pub async fn confirm_password_reset<T1, T2>(
+    State(use_case): State<Arc<PasswordResetUseCase<T1, T2>>>,
+    Json(model): Json<PasswordResetConfirmModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: PasswordResetRepository + Send + Sync,
+{
+    match use_case.confirm(model.token, model.new_password).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => {
+            if let Some(validation_errors) = e.downcast_ref::<ValidationErrors>() {
+                return validation_errors.clone().into_response();
+            }
+
+            let status = match e.downcast_ref::<CredentialsError>() {
+                Some(CredentialsError::InvalidResetToken) => StatusCode::BAD_REQUEST,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (status, e.to_string()).into_response()
+        }
+    }
+}
+
+pub fn routes(state: &AppState) -> Router {
+    let brawler_repository = Arc::new(BrawlerPostgres::new(
+        Arc::clone(&state.db_pool),
+        Arc::clone(&state.image_storage),
+    ));
+    let login_attempt_repository = LoginAttemptPostgres::new(Arc::clone(&state.db_pool));
+    let use_case = AuthenticationUseCase::new(
+        Arc::clone(&brawler_repository),
+        Arc::new(login_attempt_repository),
+        state.config.login_throttle.clone(),
+        Arc::clone(&state.jwt_keys),
+    );
 
-    Router::new()
+    let mut router = Router::new()
         .route("/login", post(login))
         .with_state(Arc::new(use_case))
+        .route("/logout", post(logout));
+
+    if state.config.features.password_reset {
+        let password_reset_repository = PasswordResetPostgres::new(Arc::clone(&state.db_pool));
+        let password_reset_use_case = PasswordResetUseCase::new(
+            brawler_repository,
+            Arc::new(password_reset_repository),
+            Arc::clone(&state.mailer),
+            state.config.password_reset.clone(),
+        );
+
+        let password_reset_routes = Router::new()
+            .route("/password-reset/request", post(request_password_reset))
+            .route("/password-reset/confirm", post(confirm_password_reset))
+            .with_state(Arc::new(password_reset_use_case));
+
+        router = router.merge(password_reset_routes);
+    }
+
+    if let Some(oidc_env) = &state.config.oidc {
+        router = router.nest("/oidc", oidc_login::routes(state, oidc_env));
+    }
+
+    router
+        .route_layer(state.rate_limiters.auth.clone())
+        .layer(Extension(state.session_cookies))
 }
@@ -0,0 +1,74 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::brawler_ratings::BrawlerRatingsUseCase,
+    domain::{
+        repositories::{
+            brawler_ratings::BrawlerRatingRepository, mission_viewing::MissionViewingRepository,
+        },
+        value_objects::brawler_rating_model::AddBrawlerRatingModel,
+    },
+    infrastructure::{
+        database::repositories::{
+            brawler_ratings::BrawlerRatingPostgres, mission_viewing::MissionViewingPostgres,
+        },
+        http::{app_state::AppState, middleware::auth::authorization},
+    },
+};
+
+pub async fn get_all<T1, T2>(
+    State(use_case): State<Arc<BrawlerRatingsUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match use_case.gets(mission_id, user_id).await {
+        Ok(ratings) => (StatusCode::OK, Json(ratings)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn rate<T1, T2>(
+    State(use_case): State<Arc<BrawlerRatingsUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, brawler_id)): Path<(i32, i32)>,
+    Json(add_rating_model): Json<AddBrawlerRatingModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match use_case
+        .rate(mission_id, user_id, brawler_id, add_rating_model)
+        .await
+    {
+        Ok(id) => (StatusCode::CREATED, Json(id)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(state: &AppState) -> Router {
+    let rating_repository = BrawlerRatingPostgres::new(Arc::clone(&state.db_pool));
+    let viewing_repository = MissionViewingPostgres::new(Arc::clone(&state.db_pool));
+    let use_case = Arc::new(BrawlerRatingsUseCase::new(
+        Arc::new(rating_repository),
+        Arc::new(viewing_repository),
+    ));
+
+    Router::new()
+        .route("/{mission_id}", get(get_all))
+        .route("/{mission_id}/{brawler_id}", post(rate))
+        .route_layer(middleware::from_fn_with_state(state.clone(), authorization))
+        .with_state(use_case)
+}
@@ -1,47 +1,75 @@
-use crate::infrastructure::http::middleware::auth::authorization;
+use crate::infrastructure::http::{
+    app_state::AppState,
+    extractors::{image_upload::ImageUpload, validated_json::ValidatedJson},
+    middleware::auth::authorization,
+    session_cookies::SessionCookies,
+};
 use crate::{
     application::use_cases::brawlers::BrawlersUseCase,
     domain::{
         repositories::brawlers::BrawlerRepository,
-        value_objects::brawler_model::{RegisterBrawlerModel, UpdateBrawlerModel},
-    },
-    infrastructure::database::{
-        postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres,
+        value_objects::{
+            brawler_model::{RegisterBrawlerModel, UpdateBrawlerModel},
+            credentials::{ChangePasswordModel, CredentialsError},
+            validation::ValidationErrors,
+        },
     },
+    infrastructure::database::repositories::brawlers::BrawlerPostgres,
 };
 use axum::{
     Extension, Json, Router,
-    extract::{Path, State},
+    extract::{DefaultBodyLimit, Path, State},
     response::IntoResponse,
-    routing::{get, post, put},
+    routing::{get, patch, post, put},
 };
+use axum_extra::extract::cookie::CookieJar;
 use std::sync::Arc;
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let repository = BrawlerPostgres::new(db_pool.clone());
-    let use_case = BrawlersUseCase::new(Arc::new(repository));
-    let state = Arc::new(use_case);
+pub fn routes(state: &AppState) -> Router {
+    let repository =
+        BrawlerPostgres::new(Arc::clone(&state.db_pool), Arc::clone(&state.image_storage));
+    let use_case = Arc::new(BrawlersUseCase::new(
+        Arc::new(repository),
+        Arc::clone(&state.image_processor),
+        Arc::clone(&state.jwt_keys),
+    ));
 
     let protected_routes = Router::new()
-        .route("/avatar", post(upload_avatar))
+        // `ImageUpload` enforces its own size limit while streaming multipart bodies.
+        .route(
+            "/avatar",
+            post(upload_avatar)
+                .layer(DefaultBodyLimit::disable())
+                .layer(state.rate_limiters.uploads.clone())
+                .delete(remove_avatar),
+        )
         .route("/update", put(update_profile))
+        .route("/password", patch(change_password))
         .route("/my-missions", get(get_missions))
-        .route_layer(axum::middleware::from_fn(authorization));
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            authorization,
+        ));
 
     Router::new()
-        .route("/register", post(register))
+        .route(
+            "/register",
+            post(register).layer(state.rate_limiters.auth.clone()),
+        )
+        .route("/{brawler_id}", get(get_profile))
         .route(
             "/missions/{mission_id}/brawlers",
             axum::routing::get(get_brawlers_by_mission_id),
         )
         .merge(protected_routes)
-        .with_state(state)
+        .layer(Extension(state.session_cookies))
+        .with_state(use_case)
 }
 
 pub async fn update_profile<T>(
     State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
     Extension(brawler_id): Extension<i32>,
-    Json(update_model): Json<UpdateBrawlerModel>,
+    ValidatedJson(update_model): ValidatedJson<UpdateBrawlerModel>,
 ) -> impl IntoResponse
 where
     T: BrawlerRepository + Send + Sync,
@@ -55,37 +83,68 @@ where
     }
 }
 
+pub async fn change_password<T>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Json(change_password_model): Json<ChangePasswordModel>,
+) -> impl IntoResponse
+where
+    T: BrawlerRepository + Send + Sync,
+{
+    match brawlers_use_case
+        .change_password(brawler_id, change_password_model)
+        .await
+    {
+        Ok(_) => axum::http::StatusCode::NO_CONTENT.into_response(),
+        Err(e) => {
+            if let Some(validation_errors) = e.downcast_ref::<ValidationErrors>() {
+                return validation_errors.clone().into_response();
+            }
+
+            let status = match e.downcast_ref::<CredentialsError>() {
+                Some(CredentialsError::WrongCurrentPassword) => {
+                    axum::http::StatusCode::UNAUTHORIZED
+                }
+                _ => axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (status, e.to_string()).into_response()
+        }
+    }
+}
+
 pub async fn register<T>(
     State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
+    Extension(session_cookies): Extension<SessionCookies>,
+    jar: CookieJar,
     Json(register_brawler_model): Json<RegisterBrawlerModel>,
 ) -> impl IntoResponse
 where
     T: BrawlerRepository + Send + Sync,
 {
     match brawlers_use_case.register(register_brawler_model).await {
-        Ok(passport) => (axum::http::StatusCode::CREATED, Json(passport)).into_response(),
-        Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Ok(passport) => (
+            axum::http::StatusCode::CREATED,
+            session_cookies.issue(jar, &passport),
+            Json(passport),
+        )
+            .into_response(),
+        Err(e) => match e.downcast_ref::<ValidationErrors>() {
+            Some(validation_errors) => validation_errors.clone().into_response(),
+            None => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        },
     }
 }
 
 pub async fn upload_avatar<T>(
     State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
     Extension(brawler_id): Extension<i32>,
-    Json(upload_avatar): Json<crate::domain::value_objects::uploaded_image::UploadBase64Image>,
+    ImageUpload(base64_image): ImageUpload,
 ) -> impl IntoResponse
 where
     T: BrawlerRepository + Send + Sync,
 {
     match brawlers_use_case
-        .upload_avatar(
-            brawler_id,
-            upload_avatar.base64_string,
-            crate::domain::value_objects::uploaded_image::UploadImageOptions {
-                folder: None,
-                public_id: None,
-                transformation: None,
-            },
-        )
+        .upload_avatar(brawler_id, base64_image)
         .await
     {
         Ok(uploaded_img) => (axum::http::StatusCode::OK, Json(uploaded_img)).into_response(),
@@ -93,6 +152,19 @@ where
     }
 }
 
+pub async fn remove_avatar<T>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: BrawlerRepository + Send + Sync,
+{
+    match brawlers_use_case.remove_avatar(brawler_id).await {
+        Ok(_) => axum::http::StatusCode::OK.into_response(),
+        Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
 pub async fn get_brawlers_by_mission_id<T>(
     State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
     Path(mission_id): Path<i32>,
@@ -121,3 +193,17 @@ where
         Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
+
+pub async fn get_profile<T>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: BrawlerRepository + Send + Sync,
+{
+    match brawlers_use_case.get_profile(brawler_id).await {
+        Ok(Some(profile)) => (axum::http::StatusCode::OK, Json(profile)).into_response(),
+        Ok(None) => (axum::http::StatusCode::NOT_FOUND, "Brawler not found").into_response(),
+        Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
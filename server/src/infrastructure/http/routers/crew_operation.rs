@@ -11,33 +11,33 @@ use std::sync::Arc;
 use crate::{
     application::use_cases::crew_operation::CrewOperationUseCase,
     domain::repositories::{
-        crew_operation::CrewOperationRepository, mission_management::MissionManagementRepository,
-        mission_viewing::MissionViewingRepository,
+        audit_log::AuditLogRepository, crew_operation::CrewOperationRepository,
+        mission_management::MissionManagementRepository, mission_viewing::MissionViewingRepository,
     },
     infrastructure::{
-        database::{
-            postgresql_connection::PgPoolSquad,
-            repositories::{
-                crew_operation::CrewOperationPostgres,
-                mission_management::MissionManagementPostgres,
-                mission_viewing::MissionViewingPostgres,
-            },
+        database::repositories::{
+            audit_log::AuditLogPostgres, crew_operation::CrewOperationPostgres,
+            mission_management::MissionManagementPostgres, mission_viewing::MissionViewingPostgres,
+        },
+        http::{
+            app_state::AppState, extractors::audit_context::Audit, middleware::auth::authorization,
         },
-        http::middleware::auth::authorization,
     },
 };
 
-pub async fn join<T1, T2, T3>(
-    State(use_case): State<Arc<CrewOperationUseCase<T1, T2, T3>>>,
+pub async fn join<T1, T2, T3, T4>(
+    State(use_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4>>>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
+    Audit(audit): Audit,
 ) -> impl IntoResponse
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
     T3: MissionManagementRepository + Send + Sync,
+    T4: AuditLogRepository + Send + Sync,
 {
-    match use_case.join(mission_id, user_id).await {
+    match use_case.join(mission_id, user_id, audit).await {
         Ok(_) => (
             StatusCode::OK,
             format!("Join Mission_id:{} completed", mission_id),
@@ -60,17 +60,19 @@ where
     }
 }
 
-pub async fn leave<T1, T2, T3>(
-    State(use_case): State<Arc<CrewOperationUseCase<T1, T2, T3>>>,
+pub async fn leave<T1, T2, T3, T4>(
+    State(use_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4>>>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
+    Audit(audit): Audit,
 ) -> impl IntoResponse
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
     T3: MissionManagementRepository + Send + Sync,
+    T4: AuditLogRepository + Send + Sync,
 {
-    match use_case.leave(mission_id, user_id).await {
+    match use_case.leave(mission_id, user_id, audit).await {
         Ok(_) => (
             StatusCode::OK,
             format!("Leave Mission_id:{} completed", mission_id),
@@ -80,17 +82,22 @@ where
     }
 }
 
-pub async fn kick_member<T1, T2, T3>(
-    State(use_case): State<Arc<CrewOperationUseCase<T1, T2, T3>>>,
+pub async fn kick_member<T1, T2, T3, T4>(
+    State(use_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4>>>,
     Extension(user_id): Extension<i32>, // This is the requester (Chief)
     Path((mission_id, brawler_id)): Path<(i32, i32)>, // Target brawler
+    Audit(audit): Audit,
 ) -> impl IntoResponse
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
     T3: MissionManagementRepository + Send + Sync,
+    T4: AuditLogRepository + Send + Sync,
 {
-    match use_case.kick_member(mission_id, brawler_id, user_id).await {
+    match use_case
+        .kick_member(mission_id, brawler_id, user_id, audit)
+        .await
+    {
         Ok(_) => (
             StatusCode::OK,
             format!(
@@ -103,20 +110,24 @@ where
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let crew_repo = CrewOperationPostgres::new(db_pool.clone());
-    let viewing_repo = MissionViewingPostgres::new(db_pool.clone());
-    let management_repo = MissionManagementPostgres::new(db_pool);
+pub fn routes(state: &AppState) -> Router {
+    let crew_repo = CrewOperationPostgres::new(Arc::clone(&state.db_pool));
+    let viewing_repo = MissionViewingPostgres::new(Arc::clone(&state.db_pool));
+    let management_repo = MissionManagementPostgres::new(Arc::clone(&state.db_pool));
+    let audit_log_repo = AuditLogPostgres::new(Arc::clone(&state.db_pool));
     let use_case = CrewOperationUseCase::new(
         Arc::new(crew_repo),
         Arc::new(viewing_repo),
         Arc::new(management_repo),
+        Arc::new(audit_log_repo),
     );
 
     Router::new()
         .route("/join/{mission_id}", post(join))
         .route("/leave/{mission_id}", delete(leave))
         .route("/kick/{mission_id}/{brawler_id}", delete(kick_member))
-        .route_layer(middleware::from_fn(authorization))
+        // Inside `authorization`, so crew actions are counted per brawler.
+        .route_layer(state.rate_limiters.crew.clone())
+        .route_layer(middleware::from_fn_with_state(state.clone(), authorization))
         .with_state(Arc::new(use_case))
 }
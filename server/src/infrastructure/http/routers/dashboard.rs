@@ -8,10 +8,8 @@ use crate::{
     application::use_cases::dashboard::DashboardUseCase,
     domain::repositories::dashboard::DashboardRepository,
     infrastructure::{
-        database::{
-            postgresql_connection::PgPoolSquad, repositories::dashboard::DashboardPostgres,
-        },
-        http::middleware::auth::authorization,
+        database::repositories::dashboard::DashboardPostgres,
+        http::{app_state::AppState, middleware::auth::authorization},
     },
 };
 
@@ -22,8 +20,8 @@ pub struct UserDashboardResponse {
     pub open_missions: Vec<crate::domain::value_objects::mission_model::MissionModel>,
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let repository = DashboardPostgres::new(Arc::clone(&db_pool));
+pub fn routes(state: &AppState) -> Router {
+    let repository = DashboardPostgres::new(Arc::clone(&state.db_pool));
     let use_case = Arc::new(DashboardUseCase::new(Arc::new(repository)));
 
     // Public routes
@@ -35,7 +33,10 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     // Protected routes - need separate state clone
     let protected_routes = Router::new()
         .route("/me", get(get_user_dashboard))
-        .route_layer(axum::middleware::from_fn(authorization))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            authorization,
+        ))
         .with_state(use_case);
 
     public_routes.merge(protected_routes)
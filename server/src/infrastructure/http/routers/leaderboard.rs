@@ -0,0 +1,45 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+};
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::leaderboard::LeaderboardUseCase,
+    domain::{
+        repositories::leaderboard::LeaderboardRepository,
+        value_objects::leaderboard::{LeaderboardCategory, LeaderboardQuery},
+    },
+    infrastructure::{
+        database::repositories::leaderboard::LeaderboardPostgres, http::app_state::AppState,
+    },
+};
+
+pub fn routes(state: &AppState) -> Router {
+    let repository = LeaderboardPostgres::new(Arc::clone(&state.db_pool));
+    let use_case = Arc::new(LeaderboardUseCase::new(
+        Arc::new(repository),
+        state.config.leaderboard.min_sample,
+    ));
+
+    Router::new()
+        .route("/{category}", get(get_leaderboard))
+        .with_state(use_case)
+}
+
+pub async fn get_leaderboard<T>(
+    State(use_case): State<Arc<LeaderboardUseCase<T>>>,
+    Path(category): Path<LeaderboardCategory>,
+    Query(query): Query<LeaderboardQuery>,
+) -> impl IntoResponse
+where
+    T: LeaderboardRepository + Send + Sync,
+{
+    match use_case.get(category, query).await {
+        Ok(leaderboard) => (StatusCode::OK, Json(leaderboard)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
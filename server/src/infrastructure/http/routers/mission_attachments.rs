@@ -0,0 +1,100 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{DefaultBodyLimit, Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+};
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::mission_attachments::MissionAttachmentsUseCase,
+    domain::repositories::{
+        mission_attachments::MissionAttachmentRepository, mission_viewing::MissionViewingRepository,
+    },
+    infrastructure::{
+        database::repositories::{
+            mission_attachments::MissionAttachmentPostgres, mission_viewing::MissionViewingPostgres,
+        },
+        http::{
+            app_state::AppState, extractors::image_upload::ImageUpload,
+            middleware::auth::authorization,
+        },
+    },
+};
+
+pub async fn get_all<T1, T2>(
+    State(use_case): State<Arc<MissionAttachmentsUseCase<T1, T2>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match use_case.gets(mission_id).await {
+        Ok(attachments) => (StatusCode::OK, Json(attachments)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn upload<T1, T2>(
+    State(use_case): State<Arc<MissionAttachmentsUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    ImageUpload(base64_image): ImageUpload,
+) -> impl IntoResponse
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match use_case.upload(mission_id, user_id, base64_image).await {
+        Ok(attachment) => (StatusCode::CREATED, Json(attachment)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove<T1, T2>(
+    State(use_case): State<Arc<MissionAttachmentsUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, attachment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match use_case.remove(mission_id, attachment_id, user_id).await {
+        Ok(_) => StatusCode::OK.into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(state: &AppState) -> Router {
+    let attachment_repository = MissionAttachmentPostgres::new(
+        Arc::clone(&state.db_pool),
+        Arc::clone(&state.image_storage),
+    );
+    let viewing_repository = MissionViewingPostgres::new(Arc::clone(&state.db_pool));
+    let use_case = Arc::new(MissionAttachmentsUseCase::new(
+        Arc::new(attachment_repository),
+        Arc::new(viewing_repository),
+    ));
+
+    let public_routes = Router::new()
+        .route("/{mission_id}", get(get_all))
+        .with_state(Arc::clone(&use_case));
+
+    let protected_routes = Router::new()
+        // `ImageUpload` enforces its own size limit while streaming multipart bodies.
+        .route(
+            "/{mission_id}",
+            post(upload)
+                .layer(DefaultBodyLimit::disable())
+                .layer(state.rate_limiters.uploads.clone()),
+        )
+        .route("/{mission_id}/{attachment_id}", delete(remove))
+        .route_layer(middleware::from_fn_with_state(state.clone(), authorization))
+        .with_state(use_case);
+
+    public_routes.merge(protected_routes)
+}
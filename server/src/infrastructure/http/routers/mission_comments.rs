@@ -0,0 +1,122 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch, post},
+};
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::mission_comments::MissionCommentsUseCase,
+    domain::{
+        repositories::{
+            mission_comments::MissionCommentRepository, mission_viewing::MissionViewingRepository,
+        },
+        value_objects::mission_comment_model::{
+            AddMissionCommentModel, CommentQuery, EditMissionCommentModel,
+        },
+    },
+    infrastructure::{
+        database::repositories::{
+            mission_comments::MissionCommentPostgres, mission_viewing::MissionViewingPostgres,
+        },
+        http::{
+            app_state::AppState,
+            middleware::auth::{authorization, optional_authorization},
+        },
+    },
+};
+
+pub async fn get_all<T1, T2>(
+    State(use_case): State<Arc<MissionCommentsUseCase<T1, T2>>>,
+    Extension(viewer_id): Extension<Option<i32>>,
+    Path(mission_id): Path<i32>,
+    Query(query): Query<CommentQuery>,
+) -> impl IntoResponse
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match use_case.gets(mission_id, viewer_id, query).await {
+        Ok(page) => (StatusCode::OK, Json(page)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn add<T1, T2>(
+    State(use_case): State<Arc<MissionCommentsUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<AddMissionCommentModel>,
+) -> impl IntoResponse
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match use_case.add(mission_id, user_id, model).await {
+        Ok(id) => (StatusCode::CREATED, Json(id)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn edit<T1, T2>(
+    State(use_case): State<Arc<MissionCommentsUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, comment_id)): Path<(i32, i32)>,
+    Json(model): Json<EditMissionCommentModel>,
+) -> impl IntoResponse
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match use_case.edit(mission_id, comment_id, user_id, model).await {
+        Ok(id) => (StatusCode::OK, Json(id)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove<T1, T2>(
+    State(use_case): State<Arc<MissionCommentsUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, comment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match use_case.remove(mission_id, comment_id, user_id).await {
+        Ok(_) => StatusCode::OK.into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(state: &AppState) -> Router {
+    let comment_repository = MissionCommentPostgres::new(Arc::clone(&state.db_pool));
+    let viewing_repository = MissionViewingPostgres::new(Arc::clone(&state.db_pool));
+    let use_case = Arc::new(MissionCommentsUseCase::new(
+        Arc::new(comment_repository),
+        Arc::new(viewing_repository),
+        state.config.comment.public_when_open,
+    ));
+
+    let public_routes = Router::new()
+        .route("/{mission_id}/comments", get(get_all))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            optional_authorization,
+        ))
+        .with_state(Arc::clone(&use_case));
+
+    let protected_routes = Router::new()
+        .route("/{mission_id}/comments", post(add))
+        .route(
+            "/{mission_id}/comments/{comment_id}",
+            patch(edit).delete(remove),
+        )
+        .route_layer(middleware::from_fn_with_state(state.clone(), authorization))
+        .with_state(use_case);
+
+    public_routes.merge(protected_routes)
+}
@@ -6,88 +6,131 @@ use axum::{
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::{delete, patch, post},
+    routing::{delete, get, patch, post},
 };
 
 use crate::{
     application::use_cases::mission_management::MissionManagementUseCase,
     domain::{
         repositories::{
-            mission_management::MissionManagementRepository,
+            audit_log::AuditLogRepository, mission_management::MissionManagementRepository,
             mission_viewing::MissionViewingRepository,
         },
         value_objects::mission_model::{AddMissionModel, EditMissionModel},
     },
     infrastructure::{
-        database::{
-            postgresql_connection::PgPoolSquad,
-            repositories::{
-                mission_management::MissionManagementPostgres,
-                mission_viewing::MissionViewingPostgres,
-            },
+        database::repositories::{
+            audit_log::AuditLogPostgres, mission_management::MissionManagementPostgres,
+            mission_viewing::MissionViewingPostgres,
+        },
+        http::{
+            app_state::AppState,
+            extractors::{audit_context::Audit, validated_json::ValidatedJson},
+            middleware::auth::authorization,
         },
-        http::middleware::auth::authorization,
     },
 };
 
-pub async fn add<T1, T2>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+pub async fn add<T1, T2, T3>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
     Extension(user_id): Extension<i32>,
-    Json(model): Json<AddMissionModel>,
+    Audit(audit): Audit,
+    ValidatedJson(model): ValidatedJson<AddMissionModel>,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: AuditLogRepository + Send + Sync,
 {
-    match user_case.add(user_id, model).await {
+    match user_case.add(user_id, model, audit).await {
         Ok(passport) => (StatusCode::OK, Json(passport)).into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
 
-pub async fn edit<T1, T2>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+pub async fn edit<T1, T2, T3>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
-    Json(model): Json<EditMissionModel>,
+    Audit(audit): Audit,
+    ValidatedJson(model): ValidatedJson<EditMissionModel>,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: AuditLogRepository + Send + Sync,
 {
-    match user_case.edit(mission_id, user_id, model).await {
+    match user_case.edit(mission_id, user_id, model, audit).await {
         Ok(passport) => (StatusCode::OK, Json(passport)).into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
 
-pub async fn remove<T1, T2>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+pub async fn remove<T1, T2, T3>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
+    Audit(audit): Audit,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: AuditLogRepository + Send + Sync,
 {
-    match user_case.remove(mission_id, user_id).await {
+    match user_case.remove(mission_id, user_id, audit).await {
         Ok(passport) => (StatusCode::OK, Json(passport)).into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let management_repository = MissionManagementPostgres::new(db_pool.clone());
-    let viewing_repository = MissionViewingPostgres::new(db_pool);
+pub async fn trash<T1, T2, T3>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AuditLogRepository + Send + Sync,
+{
+    match user_case.trash(user_id).await {
+        Ok(missions) => (StatusCode::OK, Json(missions)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn restore<T1, T2, T3>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Audit(audit): Audit,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AuditLogRepository + Send + Sync,
+{
+    match user_case.restore(mission_id, user_id, audit).await {
+        Ok(id) => (StatusCode::OK, Json(id)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(state: &AppState) -> Router {
+    let management_repository = MissionManagementPostgres::new(Arc::clone(&state.db_pool));
+    let viewing_repository = MissionViewingPostgres::new(Arc::clone(&state.db_pool));
+    let audit_log_repository = AuditLogPostgres::new(Arc::clone(&state.db_pool));
     let use_case = MissionManagementUseCase::new(
         Arc::new(management_repository),
         Arc::new(viewing_repository),
+        Arc::new(audit_log_repository),
     );
 
     Router::new()
         .route("/", post(add))
         .route("/{mission_id}", patch(edit))
         .route("/{mission_id}", delete(remove))
-        .route_layer(middleware::from_fn(authorization))
+        .route("/trash", get(trash))
+        .route("/{mission_id}/restore", patch(restore))
+        .route_layer(middleware::from_fn_with_state(state.clone(), authorization))
         .with_state(Arc::new(use_case))
 }
@@ -0,0 +1,151 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch, post},
+};
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::mission_objectives::MissionObjectivesUseCase,
+    domain::{
+        repositories::{
+            mission_objectives::MissionObjectiveRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::mission_objective_model::{
+            AddMissionObjectiveModel, EditMissionObjectiveModel,
+        },
+    },
+    infrastructure::{
+        database::repositories::{
+            mission_objectives::MissionObjectivePostgres, mission_viewing::MissionViewingPostgres,
+        },
+        http::{app_state::AppState, middleware::auth::authorization},
+    },
+};
+
+pub async fn get_all<T1, T2>(
+    State(use_case): State<Arc<MissionObjectivesUseCase<T1, T2>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match use_case.gets(mission_id).await {
+        Ok(objectives) => (StatusCode::OK, Json(objectives)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn add<T1, T2>(
+    State(use_case): State<Arc<MissionObjectivesUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<AddMissionObjectiveModel>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match use_case.add(mission_id, user_id, model).await {
+        Ok(id) => (StatusCode::CREATED, Json(id)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn edit<T1, T2>(
+    State(use_case): State<Arc<MissionObjectivesUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, objective_id)): Path<(i32, i32)>,
+    Json(model): Json<EditMissionObjectiveModel>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match use_case
+        .edit(mission_id, objective_id, user_id, model)
+        .await
+    {
+        Ok(id) => (StatusCode::OK, Json(id)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove<T1, T2>(
+    State(use_case): State<Arc<MissionObjectivesUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, objective_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match use_case.remove(mission_id, objective_id, user_id).await {
+        Ok(_) => StatusCode::OK.into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn check<T1, T2>(
+    State(use_case): State<Arc<MissionObjectivesUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, objective_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match use_case
+        .set_completed(mission_id, objective_id, user_id, true)
+        .await
+    {
+        Ok(id) => (StatusCode::OK, Json(id)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn uncheck<T1, T2>(
+    State(use_case): State<Arc<MissionObjectivesUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, objective_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match use_case
+        .set_completed(mission_id, objective_id, user_id, false)
+        .await
+    {
+        Ok(id) => (StatusCode::OK, Json(id)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(state: &AppState) -> Router {
+    let objective_repository = MissionObjectivePostgres::new(Arc::clone(&state.db_pool));
+    let viewing_repository = MissionViewingPostgres::new(Arc::clone(&state.db_pool));
+    let use_case = Arc::new(MissionObjectivesUseCase::new(
+        Arc::new(objective_repository),
+        Arc::new(viewing_repository),
+    ));
+
+    let public_routes = Router::new()
+        .route("/{mission_id}", get(get_all))
+        .with_state(Arc::clone(&use_case));
+
+    let protected_routes = Router::new()
+        .route("/{mission_id}", post(add))
+        .route("/{mission_id}/{objective_id}", patch(edit).delete(remove))
+        .route("/{mission_id}/{objective_id}/check", patch(check))
+        .route("/{mission_id}/{objective_id}/uncheck", patch(uncheck))
+        .route_layer(middleware::from_fn_with_state(state.clone(), authorization))
+        .with_state(use_case);
+
+    public_routes.merge(protected_routes)
+}
@@ -13,8 +13,9 @@ use crate::{
         repositories::mission_viewing::MissionViewingRepository,
         value_objects::mission_filter::MissionFilter,
     },
-    infrastructure::database::{
-        postgresql_connection::PgPoolSquad, repositories::mission_viewing::MissionViewingPostgres,
+    infrastructure::{
+        database::repositories::mission_viewing::MissionViewingPostgres,
+        http::{app_state::AppState, routers::mission_comments},
     },
 };
 
@@ -58,8 +59,8 @@ where
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let viewing_repository = MissionViewingPostgres::new(db_pool);
+pub fn routes(state: &AppState) -> Router {
+    let viewing_repository = MissionViewingPostgres::new(Arc::clone(&state.db_pool));
     let use_case = MissionViewingUseCase::new(Arc::new(viewing_repository));
 
     Router::new()
@@ -68,4 +69,5 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         .route("/{mission_id}", get(get_one))
         .route("/{mission_id}/crew", get(get_mission_crew))
         .with_state(Arc::new(use_case))
+        .merge(mission_comments::routes(state))
 }
@@ -0,0 +1,208 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Query, State},
+    http::{StatusCode, header::LOCATION},
+    response::IntoResponse,
+    routing::{get, post},
+};
+use axum_extra::extract::cookie::{Cookie, CookieJar, SameSite};
+use tracing::error;
+
+use crate::{
+    application::use_cases::oidc_login::{OIDC_FLOW_TTL_SECONDS, OidcLoginUseCase},
+    config::config_model::OidcEnv,
+    domain::{
+        repositories::{
+            brawler_identities::BrawlerIdentityRepository, brawlers::BrawlerRepository,
+        },
+        value_objects::external_identity::{
+            IDENTITY_ALREADY_LINKED, IDENTITY_NOT_LINKED, INVALID_OIDC_STATE, OIDC_SIGN_IN_REFUSED,
+            OidcAuthorizationModel, OidcCallbackQuery,
+        },
+    },
+    infrastructure::{
+        database::repositories::{
+            brawler_identities::BrawlerIdentityPostgres, brawlers::BrawlerPostgres,
+        },
+        http::{
+            app_state::AppState, middleware::auth::authorization, session_cookies::SessionCookies,
+        },
+        oidc::OidcProvider,
+    },
+};
+
+const FLOW_COOKIE: &str = "oidc_flow";
+const FLOW_COOKIE_PATH: &str = "/api/authentication/oidc";
+
+/// Marked `Secure` exactly when the session cookies are (`SESSION_COOKIE_SECURE`).
+#[derive(Debug, Clone, Copy)]
+pub struct FlowCookiePolicy {
+    secure: bool,
+}
+
+impl FlowCookiePolicy {
+    fn cookie(&self, value: String) -> Cookie<'static> {
+        Cookie::build((FLOW_COOKIE, value))
+            .path(FLOW_COOKIE_PATH)
+            .http_only(true)
+            .secure(self.secure)
+            // Lax, so the cookie comes back on the provider's top-level redirect.
+            .same_site(SameSite::Lax)
+            .max_age(cookie::time::Duration::seconds(OIDC_FLOW_TTL_SECONDS))
+            .build()
+    }
+
+    fn removal(&self) -> Cookie<'static> {
+        Cookie::build(FLOW_COOKIE).path(FLOW_COOKIE_PATH).build()
+    }
+}
+
+/// `OIDC_FRONTEND_URL`, where the callback hands the browser back to the frontend.
+#[derive(Debug, Clone)]
+pub struct FrontendRedirect {
+    url: Arc<str>,
+}
+
+impl FrontendRedirect {
+    fn to(&self, error: Option<&str>) -> String {
+        match error {
+            Some(error) => {
+                let separator = if self.url.contains('?') { '&' } else { '?' };
+                format!("{}{}error={}", self.url, separator, error)
+            }
+            None => self.url.to_string(),
+        }
+    }
+}
+
+pub fn routes(state: &AppState, oidc_env: &OidcEnv) -> Router {
+    let brawler_repository =
+        BrawlerPostgres::new(Arc::clone(&state.db_pool), Arc::clone(&state.image_storage));
+    let brawler_identity_repository = BrawlerIdentityPostgres::new(Arc::clone(&state.db_pool));
+    let use_case = OidcLoginUseCase::new(
+        Arc::new(brawler_repository),
+        Arc::new(brawler_identity_repository),
+        Arc::new(OidcProvider::new(oidc_env.clone())),
+        Arc::clone(&state.jwt_keys),
+        oidc_env.auto_register,
+    );
+    let cookie_policy = FlowCookiePolicy {
+        secure: state.config.session_cookie.secure,
+    };
+    let frontend = FrontendRedirect {
+        url: Arc::from(oidc_env.frontend_url.as_str()),
+    };
+
+    let link_routes =
+        Router::new()
+            .route("/link", post(link))
+            .route_layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                authorization,
+            ));
+
+    Router::new()
+        .route("/login", get(login))
+        .route("/callback", get(callback))
+        .merge(link_routes)
+        .layer(Extension(cookie_policy))
+        .layer(Extension(frontend))
+        .layer(Extension(state.session_cookies))
+        .with_state(Arc::new(use_case))
+}
+
+/// Browser entry point: redirects to the provider with a fresh flow cookie.
+pub async fn login<T1, T2>(
+    State(use_case): State<Arc<OidcLoginUseCase<T1, T2>>>,
+    Extension(cookie_policy): Extension<FlowCookiePolicy>,
+    jar: CookieJar,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: BrawlerIdentityRepository + Send + Sync,
+{
+    match use_case.start(None).await {
+        Ok(flow) => (
+            StatusCode::SEE_OTHER,
+            jar.add(cookie_policy.cookie(flow.flow_token)),
+            [(LOCATION, flow.authorization_url)],
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+/// For a signed-in brawler: answers with the provider URL to navigate to, and the callback
+/// then links that identity to the brawler instead of logging in.
+pub async fn link<T1, T2>(
+    State(use_case): State<Arc<OidcLoginUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Extension(cookie_policy): Extension<FlowCookiePolicy>,
+    jar: CookieJar,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: BrawlerIdentityRepository + Send + Sync,
+{
+    match use_case.start(Some(brawler_id)).await {
+        Ok(flow) => (
+            StatusCode::OK,
+            jar.add(cookie_policy.cookie(flow.flow_token)),
+            Json(OidcAuthorizationModel {
+                authorization_url: flow.authorization_url,
+            }),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+/// The provider's redirect lands here in the browser, so every outcome is a `303` back to the
+/// frontend: signed in through the session cookies, or with `?error=<code>`.
+pub async fn callback<T1, T2>(
+    State(use_case): State<Arc<OidcLoginUseCase<T1, T2>>>,
+    Extension(cookie_policy): Extension<FlowCookiePolicy>,
+    Extension(frontend): Extension<FrontendRedirect>,
+    Extension(session_cookies): Extension<SessionCookies>,
+    jar: CookieJar,
+    Query(query): Query<OidcCallbackQuery>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: BrawlerIdentityRepository + Send + Sync,
+{
+    let flow_token = jar
+        .get(FLOW_COOKIE)
+        .map(|cookie| cookie.value().to_string());
+    // The flow is single-use whatever the outcome.
+    let jar = jar.remove(cookie_policy.removal());
+
+    match use_case.finish(query, flow_token).await {
+        Ok(passport) => (
+            StatusCode::SEE_OTHER,
+            session_cookies.issue(jar, &passport),
+            [(LOCATION, frontend.to(None))],
+        )
+            .into_response(),
+        Err(e) => {
+            let error = match e.to_string().as_str() {
+                INVALID_OIDC_STATE => "invalid_state",
+                OIDC_SIGN_IN_REFUSED => "sign_in_refused",
+                IDENTITY_NOT_LINKED => "not_linked",
+                IDENTITY_ALREADY_LINKED => "already_linked",
+                _ => {
+                    error!("OIDC callback failed: {}", e);
+                    "server_error"
+                }
+            };
+            (
+                StatusCode::SEE_OTHER,
+                jar,
+                [(LOCATION, frontend.to(Some(error)))],
+            )
+                .into_response()
+        }
+    }
+}
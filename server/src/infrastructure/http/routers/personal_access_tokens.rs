@@ -0,0 +1,88 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get},
+};
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::personal_access_tokens::PersonalAccessTokensUseCase,
+    domain::{
+        repositories::personal_access_tokens::PersonalAccessTokenRepository,
+        value_objects::personal_access_tokens::{
+            CreatePersonalAccessTokenModel, TOKEN_NAME_TAKEN, TOKEN_NOT_FOUND,
+        },
+    },
+    infrastructure::http::{
+        app_state::AppState, extractors::validated_json::ValidatedJson,
+        middleware::auth::authorization,
+    },
+};
+
+pub async fn get_all<T>(
+    State(use_case): State<Arc<PersonalAccessTokensUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: PersonalAccessTokenRepository + Send + Sync,
+{
+    match use_case.list(brawler_id).await {
+        Ok(tokens) => (StatusCode::OK, Json(tokens)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn create<T>(
+    State(use_case): State<Arc<PersonalAccessTokensUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    ValidatedJson(model): ValidatedJson<CreatePersonalAccessTokenModel>,
+) -> impl IntoResponse
+where
+    T: PersonalAccessTokenRepository + Send + Sync,
+{
+    match use_case.create(brawler_id, model).await {
+        Ok(created) => (StatusCode::CREATED, Json(created)).into_response(),
+        Err(e) => {
+            let error_message = e.to_string();
+            if error_message == TOKEN_NAME_TAKEN {
+                (StatusCode::CONFLICT, error_message).into_response()
+            } else {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+        }
+    }
+}
+
+pub async fn revoke<T>(
+    State(use_case): State<Arc<PersonalAccessTokensUseCase<T>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(token_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: PersonalAccessTokenRepository + Send + Sync,
+{
+    match use_case.revoke(brawler_id, token_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => {
+            let error_message = e.to_string();
+            if error_message == TOKEN_NOT_FOUND {
+                (StatusCode::NOT_FOUND, error_message).into_response()
+            } else {
+                (StatusCode::INTERNAL_SERVER_ERROR, error_message).into_response()
+            }
+        }
+    }
+}
+
+/// Managing tokens takes a signed-in brawler; the `authorization` middleware refuses personal
+/// access tokens here, so a leaked token can't mint more.
+pub fn routes(state: &AppState) -> Router {
+    Router::new()
+        .route("/", get(get_all).post(create))
+        .route("/{token_id}", delete(revoke))
+        .route_layer(middleware::from_fn_with_state(state.clone(), authorization))
+        .with_state(Arc::clone(&state.personal_access_tokens))
+}
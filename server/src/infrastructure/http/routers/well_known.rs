@@ -0,0 +1,26 @@
+use axum::{
+    Json, Router,
+    extract::State,
+    http::{StatusCode, header::CACHE_CONTROL},
+    response::IntoResponse,
+    routing::get,
+};
+use std::sync::Arc;
+
+use crate::infrastructure::{http::app_state::AppState, jwt::keys::JwtKeys};
+
+pub fn routes(state: &AppState) -> Router {
+    Router::new()
+        .route("/jwks.json", get(jwks))
+        .with_state(Arc::clone(&state.jwt_keys))
+}
+
+/// Public keys other services use to verify brawler tokens; empty while signing with HS256.
+pub async fn jwks(State(jwt_keys): State<Arc<JwtKeys>>) -> impl IntoResponse {
+    (
+        StatusCode::OK,
+        [(CACHE_CONTROL, "public, max-age=300")],
+        Json(jwt_keys.jwks().clone()),
+    )
+        .into_response()
+}
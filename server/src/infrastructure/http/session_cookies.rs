@@ -0,0 +1,107 @@
+use std::convert::Infallible;
+
+use axum::{
+    http::HeaderValue,
+    response::{IntoResponseParts, ResponseParts},
+};
+use axum_extra::extract::cookie::{Cookie, CookieJar, SameSite};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+
+use crate::{
+    config::config_model::{CookieSameSite, SessionCookieEnv},
+    infrastructure::jwt::jwt_model::Passport,
+};
+
+/// Read by the `authorization` middleware when a request has no `Authorization` header.
+pub const SESSION_COOKIE: &str = "token";
+/// Not HttpOnly: the frontend echoes it back in `CSRF_HEADER` on mutating requests.
+pub const CSRF_COOKIE: &str = "csrf_token";
+pub const CSRF_HEADER: &str = "x-csrf-token";
+
+/// Sets and clears the session cookies according to `SESSION_COOKIE_*`; a no-op when cookie
+/// sessions are off, so handlers can always go through it.
+#[derive(Debug, Clone, Copy)]
+pub struct SessionCookies {
+    enabled: bool,
+    secure: bool,
+    same_site: SameSite,
+    /// Days, matching the access token's `JWT_TTL`.
+    ttl: i64,
+}
+
+impl SessionCookies {
+    pub fn new(session_cookie_env: &SessionCookieEnv, ttl: i64) -> Self {
+        Self {
+            enabled: session_cookie_env.enabled,
+            secure: session_cookie_env.secure,
+            same_site: match session_cookie_env.same_site {
+                CookieSameSite::Strict => SameSite::Strict,
+                CookieSameSite::Lax => SameSite::Lax,
+                CookieSameSite::None => SameSite::None,
+            },
+            ttl,
+        }
+    }
+
+    /// Stores the passport's access token in the HttpOnly session cookie next to a fresh CSRF
+    /// token, which is also sent in `CSRF_HEADER` for frontends that can't read the cookie.
+    pub fn issue(&self, jar: CookieJar, passport: &Passport) -> IssuedSession {
+        if !self.enabled {
+            return IssuedSession {
+                jar,
+                csrf_token: None,
+            };
+        }
+
+        let csrf_token = URL_SAFE_NO_PAD.encode(rand::random::<[u8; 32]>());
+        let jar = jar
+            .add(self.cookie(SESSION_COOKIE, passport.access_token.clone(), true))
+            .add(self.cookie(CSRF_COOKIE, csrf_token.clone(), false));
+
+        IssuedSession {
+            jar,
+            csrf_token: Some(csrf_token),
+        }
+    }
+
+    /// Clears both cookies even when cookie sessions are off, so a logout after turning
+    /// them off still cleans up browsers. The removals carry the same attributes as the
+    /// originals, since some clients won't let a plain cookie replace an HttpOnly one.
+    pub fn clear(&self, jar: CookieJar) -> CookieJar {
+        jar.remove(self.cookie(SESSION_COOKIE, String::new(), true))
+            .remove(self.cookie(CSRF_COOKIE, String::new(), false))
+    }
+
+    fn cookie(&self, name: &'static str, value: String, http_only: bool) -> Cookie<'static> {
+        Cookie::build((name, value))
+            .path("/")
+            .http_only(http_only)
+            .secure(self.secure)
+            .same_site(self.same_site)
+            .max_age(cookie::time::Duration::days(self.ttl))
+            .build()
+    }
+}
+
+/// The cookies to set, plus the CSRF token header, for a response that signs a brawler in.
+pub struct IssuedSession {
+    jar: CookieJar,
+    csrf_token: Option<String>,
+}
+
+impl IntoResponseParts for IssuedSession {
+    type Error = Infallible;
+
+    fn into_response_parts(self, res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        let mut res = self.jar.into_response_parts(res)?;
+
+        if let Some(value) = self
+            .csrf_token
+            .and_then(|token| HeaderValue::from_str(&token).ok())
+        {
+            res.headers_mut().insert(CSRF_HEADER, value);
+        }
+
+        Ok(res)
+    }
+}
@@ -0,0 +1,84 @@
+use std::io::Cursor;
+
+use anyhow::{Context, Result};
+use base64::{Engine, engine::general_purpose};
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader, Limits, imageops::FilterType};
+
+use crate::domain::{
+    repositories::image_processor::ImageProcessor, value_objects::base64_image::Base64Image,
+};
+
+const MIN_DIMENSION: u32 = 64;
+const MAX_DIMENSION: u32 = 4096;
+
+/// Decodes with the `image` crate and re-encodes every variant as PNG.
+pub struct PngImageProcessor;
+
+impl ImageProcessor for PngImageProcessor {
+    /// Decodes the image, applies its EXIF orientation, center-crops it to a square and
+    /// re-encodes one PNG per requested size. Re-encoding drops EXIF and other metadata.
+    fn square_variants(
+        &self,
+        base64_image: &Base64Image,
+        sizes: &[u32],
+    ) -> Result<Vec<(u32, Base64Image)>> {
+        let image = decode(&base64_image.decode()?)?;
+
+        let (width, height) = (image.width(), image.height());
+        if width < MIN_DIMENSION || height < MIN_DIMENSION {
+            return Err(anyhow::anyhow!(
+                "Image must be at least {}x{} pixels",
+                MIN_DIMENSION,
+                MIN_DIMENSION
+            ));
+        }
+
+        let side = width.min(height);
+        let square = image.crop_imm((width - side) / 2, (height - side) / 2, side, side);
+
+        sizes
+            .iter()
+            .map(|&size| {
+                let resized = square.resize_exact(size, size, FilterType::Lanczos3);
+
+                let mut png = Vec::new();
+                resized
+                    .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+                    .context("Failed to encode image")?;
+
+                let variant = Base64Image::new(general_purpose::STANDARD.encode(png))?;
+                Ok((size, variant))
+            })
+            .collect()
+    }
+}
+
+fn decode(bytes: &[u8]) -> Result<DynamicImage> {
+    let mut reader = ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .context("Failed to read image")?;
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DIMENSION);
+    limits.max_image_height = Some(MAX_DIMENSION);
+    reader.limits(limits);
+
+    let mut decoder = reader
+        .into_decoder()
+        .map_err(|e| anyhow::anyhow!("Invalid image: {}", e))?;
+    let orientation = decoder
+        .orientation()
+        .map_err(|e| anyhow::anyhow!("Invalid image: {}", e))?;
+
+    let mut image = DynamicImage::from_decoder(decoder).map_err(|e| {
+        anyhow::anyhow!(
+            "Invalid image (max {}x{} pixels): {}",
+            MAX_DIMENSION,
+            MAX_DIMENSION,
+            e
+        )
+    })?;
+    image.apply_orientation(orientation);
+
+    Ok(image)
+}
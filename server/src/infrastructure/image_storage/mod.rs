@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    config::config_model::{ImageStorageBackend, ImageStorageEnv},
+    domain::repositories::image_storage::ImageStorage,
+    infrastructure::{cloudinary::CloudinaryStorage, local_storage::LocalImageStorage},
+};
+
+pub fn build(image_storage_env: &ImageStorageEnv) -> Result<Arc<dyn ImageStorage>> {
+    let image_storage: Arc<dyn ImageStorage> = match image_storage_env.backend {
+        ImageStorageBackend::Cloudinary => Arc::new(CloudinaryStorage::new(
+            image_storage_env
+                .cloudinary
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("Cloudinary ENV is not loaded"))?,
+        )),
+        ImageStorageBackend::Local => Arc::new(LocalImageStorage::new(
+            image_storage_env.local_dir.clone(),
+            image_storage_env.local_url.clone(),
+        )),
+    };
+
+    Ok(image_storage)
+}
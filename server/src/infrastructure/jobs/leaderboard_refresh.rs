@@ -0,0 +1,31 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::task::JoinHandle;
+use tracing::error;
+
+use crate::{
+    application::use_cases::leaderboard::LeaderboardUseCase,
+    config::config_model::LeaderboardEnv,
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad, repositories::leaderboard::LeaderboardPostgres,
+    },
+};
+
+/// Periodically refreshes the `leaderboard_stats` materialized view the leaderboard reads from.
+pub fn spawn(db_pool: Arc<PgPoolSquad>, leaderboard_env: LeaderboardEnv) -> JoinHandle<()> {
+    let repository = LeaderboardPostgres::new(db_pool);
+    let use_case = LeaderboardUseCase::new(Arc::new(repository), leaderboard_env.min_sample);
+
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(leaderboard_env.refresh_interval.max(1)));
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = use_case.refresh().await {
+                error!("Failed to refresh leaderboard: {}", e);
+            }
+        }
+    })
+}
@@ -0,0 +1,48 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+use crate::{
+    application::use_cases::mission_purge::MissionPurgeUseCase,
+    config::config_model::MissionTrash,
+    domain::repositories::image_storage::ImageStorage,
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            mission_attachments::MissionAttachmentPostgres,
+            mission_management::MissionManagementPostgres,
+        },
+    },
+};
+
+/// Periodically hard-deletes missions that have been in the trash longer than the retention.
+pub fn spawn(
+    db_pool: Arc<PgPoolSquad>,
+    image_storage: Arc<dyn ImageStorage>,
+    mission_trash: MissionTrash,
+) -> JoinHandle<()> {
+    let management_repository = MissionManagementPostgres::new(Arc::clone(&db_pool));
+    let attachment_repository = MissionAttachmentPostgres::new(db_pool, image_storage);
+    let use_case = MissionPurgeUseCase::new(
+        Arc::new(management_repository),
+        Arc::new(attachment_repository),
+    );
+
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(mission_trash.purge_interval.max(1)));
+
+        loop {
+            interval.tick().await;
+
+            match use_case.purge_expired(mission_trash.retention_days).await {
+                Ok(purged) if !purged.is_empty() => {
+                    info!("Purged {} deleted missions: {:?}", purged.len(), purged)
+                }
+                Ok(_) => {}
+                Err(e) => error!("Failed to purge deleted missions: {}", e),
+            }
+        }
+    })
+}
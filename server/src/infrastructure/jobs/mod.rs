@@ -0,0 +1,2 @@
+pub mod leaderboard_refresh;
+pub mod mission_purge;
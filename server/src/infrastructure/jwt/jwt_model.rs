@@ -2,9 +2,9 @@ use anyhow::Result;
 use chrono::{Duration, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::config::config_loader::get_jwt_env;
+use crate::domain::value_objects::progression::{Achievement, Level};
 
-use super::generate_token;
+use super::{generate_token, keys::JwtKeys};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Passport {
@@ -14,6 +14,9 @@ pub struct Passport {
     pub display_name: String,
     pub avatar_url: Option<String>,
     pub sub: String,
+    pub xp: i64,
+    pub level: i32,
+    pub achievements: Vec<Achievement>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,17 +27,23 @@ pub struct Claims {
 }
 
 impl Passport {
-    pub fn new(brawler_id: i32, display_name: String, avatar_url: Option<String>) -> Result<Self> {
-        let jwt_env = get_jwt_env()?;
+    pub fn new(
+        jwt_keys: &JwtKeys,
+        brawler_id: i32,
+        display_name: String,
+        avatar_url: Option<String>,
+        xp: i64,
+        achievements: Vec<Achievement>,
+    ) -> Result<Self> {
         let token_type = "Bearer".to_string();
-        let expires_in = (Utc::now() + Duration::days(jwt_env.ttl)).timestamp() as usize;
+        let expires_in = (Utc::now() + Duration::days(jwt_keys.ttl)).timestamp() as usize;
 
         let access_token_claims = Claims {
             sub: brawler_id.to_string(),
             exp: expires_in,
             iat: Utc::now().timestamp() as usize,
         };
-        let access_token = generate_token(&jwt_env.secret, &access_token_claims)?;
+        let access_token = generate_token(jwt_keys, &access_token_claims)?;
 
         Ok(Self {
             token_type,
@@ -43,6 +52,21 @@ impl Passport {
             display_name,
             avatar_url,
             sub: brawler_id.to_string(),
+            xp,
+            level: Level::from_xp(xp).level,
+            achievements,
         })
     }
 }
+
+/// Carried in the `oidc_flow` cookie between the login redirect and the provider's callback.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OidcFlowClaims {
+    pub aud: String,
+    pub exp: usize,
+    pub state: String,
+    pub nonce: String,
+    pub code_verifier: String,
+    /// Set when a signed-in brawler is linking the identity instead of logging in with it.
+    pub link_brawler_id: Option<i32>,
+}
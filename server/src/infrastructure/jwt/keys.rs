@@ -0,0 +1,198 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use anyhow::Result;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use jsonwebtoken::{
+    Algorithm, DecodingKey, EncodingKey,
+    jwk::{
+        AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm,
+        OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse, RSAKeyParameters, RSAKeyType,
+    },
+};
+use simple_asn1::ASN1Block;
+
+use crate::config::config_model::{JwtAlgorithm, JwtEnv};
+
+const PUBLIC_KEY_SUFFIX: &str = ".pub.pem";
+
+/// Signing key plus every key still accepted for verification, loaded once at startup.
+///
+/// With RS256/EdDSA, `JWT_KEYS_DIR` holds `<kid>.pem` (private, only needed for the signing
+/// key) and `<kid>.pub.pem` for each key in the JWKS. Rotating means adding a new pair,
+/// pointing `JWT_SIGNING_KEY_ID` at it, and deleting the old `.pub.pem` once its tokens expire.
+pub struct JwtKeys {
+    algorithm: Algorithm,
+    signing_key_id: Option<String>,
+    encoding_key: EncodingKey,
+    decoding_keys: HashMap<String, DecodingKey>,
+    jwks: JwkSet,
+    /// Days an access token stays valid.
+    pub ttl: i64,
+}
+
+impl JwtKeys {
+    pub fn load(jwt_env: &JwtEnv) -> Result<Self> {
+        let algorithm = match jwt_env.algorithm {
+            JwtAlgorithm::HS256 => {
+                // The shared secret has no kid and nothing to publish.
+                let mut decoding_keys = HashMap::new();
+                decoding_keys.insert(
+                    String::new(),
+                    DecodingKey::from_secret(jwt_env.secret.as_ref()),
+                );
+
+                return Ok(Self {
+                    algorithm: Algorithm::HS256,
+                    signing_key_id: None,
+                    encoding_key: EncodingKey::from_secret(jwt_env.secret.as_ref()),
+                    decoding_keys,
+                    jwks: JwkSet { keys: Vec::new() },
+                    ttl: jwt_env.ttl,
+                });
+            }
+            JwtAlgorithm::RS256 => Algorithm::RS256,
+            JwtAlgorithm::EdDSA => Algorithm::EdDSA,
+        };
+
+        let keys_dir = Path::new(&jwt_env.keys_dir);
+        let kid = &jwt_env.signing_key_id;
+
+        let private_path = keys_dir.join(format!("{}.pem", kid));
+        let private_pem = fs::read(&private_path)
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", private_path.display(), e))?;
+        let encoding_key = match algorithm {
+            Algorithm::RS256 => EncodingKey::from_rsa_pem(&private_pem)?,
+            _ => EncodingKey::from_ed_pem(&private_pem)?,
+        };
+
+        let mut decoding_keys = HashMap::new();
+        let mut jwks = Vec::new();
+        for entry in fs::read_dir(keys_dir)
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", keys_dir.display(), e))?
+        {
+            let path = entry?.path();
+            let Some(key_id) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(PUBLIC_KEY_SUFFIX))
+            else {
+                continue;
+            };
+
+            let jwk = public_key_to_jwk(&fs::read(&path)?, key_id, algorithm)
+                .map_err(|e| anyhow::anyhow!("Invalid public key {}: {}", path.display(), e))?;
+            decoding_keys.insert(key_id.to_string(), DecodingKey::from_jwk(&jwk)?);
+            jwks.push(jwk);
+        }
+
+        if !decoding_keys.contains_key(kid) {
+            return Err(anyhow::anyhow!(
+                "{} is missing; the signing key must also be published",
+                keys_dir
+                    .join(format!("{}{}", kid, PUBLIC_KEY_SUFFIX))
+                    .display()
+            ));
+        }
+
+        jwks.sort_by(|a, b| a.common.key_id.cmp(&b.common.key_id));
+
+        Ok(Self {
+            algorithm,
+            signing_key_id: Some(kid.clone()),
+            encoding_key,
+            decoding_keys,
+            jwks: JwkSet { keys: jwks },
+            ttl: jwt_env.ttl,
+        })
+    }
+
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    pub fn signing_key_id(&self) -> Option<&str> {
+        self.signing_key_id.as_deref()
+    }
+
+    pub fn encoding_key(&self) -> &EncodingKey {
+        &self.encoding_key
+    }
+
+    /// Looks the verification key up by the token's `kid`; HS256 tokens carry none.
+    pub fn decoding_key(&self, kid: Option<&str>) -> Option<&DecodingKey> {
+        match self.signing_key_id {
+            None => self.decoding_keys.get(""),
+            Some(_) => self.decoding_keys.get(kid?),
+        }
+    }
+
+    pub fn jwks(&self) -> &JwkSet {
+        &self.jwks
+    }
+}
+
+/// Reads an SPKI `PUBLIC KEY` PEM (what `openssl pkey -pubout` writes) into a JWK.
+fn public_key_to_jwk(pem: &[u8], kid: &str, algorithm: Algorithm) -> Result<Jwk> {
+    let pem = pem::parse(pem)?;
+    if pem.tag() != "PUBLIC KEY" {
+        return Err(anyhow::anyhow!(
+            "expected a PUBLIC KEY PEM, got {}",
+            pem.tag()
+        ));
+    }
+
+    let key_bytes = match simple_asn1::from_der(pem.contents())?.as_slice() {
+        [ASN1Block::Sequence(_, blocks)] => match blocks.as_slice() {
+            [_, ASN1Block::BitString(_, _, key_bytes)] => key_bytes.clone(),
+            _ => return Err(anyhow::anyhow!("malformed SubjectPublicKeyInfo")),
+        },
+        _ => return Err(anyhow::anyhow!("malformed SubjectPublicKeyInfo")),
+    };
+
+    let (key_algorithm, parameters) = match algorithm {
+        Algorithm::RS256 => {
+            let (n, e) = match simple_asn1::from_der(&key_bytes)?.as_slice() {
+                [ASN1Block::Sequence(_, blocks)] => match blocks.as_slice() {
+                    [ASN1Block::Integer(_, n), ASN1Block::Integer(_, e)] => {
+                        (n.to_bytes_be().1, e.to_bytes_be().1)
+                    }
+                    _ => return Err(anyhow::anyhow!("not an RSA public key")),
+                },
+                _ => return Err(anyhow::anyhow!("not an RSA public key")),
+            };
+
+            (
+                KeyAlgorithm::RS256,
+                AlgorithmParameters::RSA(RSAKeyParameters {
+                    key_type: RSAKeyType::RSA,
+                    n: URL_SAFE_NO_PAD.encode(n),
+                    e: URL_SAFE_NO_PAD.encode(e),
+                }),
+            )
+        }
+        _ => {
+            if key_bytes.len() != 32 {
+                return Err(anyhow::anyhow!("not an Ed25519 public key"));
+            }
+
+            (
+                KeyAlgorithm::EdDSA,
+                AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                    key_type: OctetKeyPairType::OctetKeyPair,
+                    curve: EllipticCurve::Ed25519,
+                    x: URL_SAFE_NO_PAD.encode(key_bytes),
+                }),
+            )
+        }
+    };
+
+    Ok(Jwk {
+        common: CommonParameters {
+            public_key_use: Some(PublicKeyUse::Signature),
+            key_algorithm: Some(key_algorithm),
+            key_id: Some(kid.to_string()),
+            ..Default::default()
+        },
+        algorithm: parameters,
+    })
+}
@@ -1,25 +1,53 @@
 pub mod authentication_model;
 pub mod jwt_model;
+pub mod keys;
 
 use anyhow::Result;
-use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use jsonwebtoken::{Header, Validation, decode, decode_header, encode};
+use serde::{Serialize, de::DeserializeOwned};
 
-pub fn generate_token(secret: &str, claims: &jwt_model::Claims) -> Result<String> {
-    let token = encode(
-        &Header::default(),
-        claims,
-        &EncodingKey::from_secret(secret.as_ref()),
-    )?;
+use self::keys::JwtKeys;
+
+pub fn generate_token<T: Serialize>(keys: &JwtKeys, claims: &T) -> Result<String> {
+    let mut header = Header::new(keys.algorithm());
+    header.kid = keys.signing_key_id().map(|kid| kid.to_string());
+
+    let token = encode(&header, claims, keys.encoding_key())?;
 
     Ok(token)
 }
 
-pub fn verify_token(secret: &str, token: &str) -> Result<jwt_model::Claims> {
-    let token = decode::<jwt_model::Claims>(
-        &token,
-        &DecodingKey::from_secret(secret.as_ref()),
-        &Validation::default(),
-    )?;
+/// Access tokens only; anything minted with an `aud` is rejected here.
+pub fn verify_token(keys: &JwtKeys, token: &str) -> Result<jwt_model::Claims> {
+    decode_with(keys, token, None)
+}
+
+/// Tokens minted for a single purpose, told apart from access tokens by their `aud`.
+pub fn verify_scoped_token<T: DeserializeOwned + Clone>(
+    keys: &JwtKeys,
+    token: &str,
+    audience: &str,
+) -> Result<T> {
+    decode_with(keys, token, Some(audience))
+}
+
+fn decode_with<T: DeserializeOwned + Clone>(
+    keys: &JwtKeys,
+    token: &str,
+    audience: Option<&str>,
+) -> Result<T> {
+    let header = decode_header(token)?;
+    let decoding_key = keys
+        .decoding_key(header.kid.as_deref())
+        .ok_or_else(|| anyhow::anyhow!("Unknown signing key"))?;
+
+    let mut validation = Validation::new(keys.algorithm());
+    if let Some(audience) = audience {
+        validation.set_audience(&[audience]);
+        validation.set_required_spec_claims(&["exp", "aud"]);
+    }
+
+    let token = decode::<T>(token, decoding_key, &validation)?;
 
     Ok(token.claims)
 }
@@ -0,0 +1,89 @@
+use crate::domain::repositories::image_storage::ImageStorage;
+use crate::domain::value_objects::base64_image::Base64Image;
+use crate::domain::value_objects::uploaded_image::{UploadImageOptions, UploadedImage};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::path::{Component, Path, PathBuf};
+
+/// Stores images on the local disk; files are served back by `ServeDir` under `base_url`.
+/// Cloudinary transformations are not applied here.
+pub struct LocalImageStorage {
+    root: PathBuf,
+    base_url: String,
+}
+
+impl LocalImageStorage {
+    pub fn new(root: impl Into<PathBuf>, base_url: impl Into<String>) -> Self {
+        Self {
+            root: root.into(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    fn resolve(&self, public_id: &str) -> Result<PathBuf> {
+        let relative = Path::new(public_id);
+        let is_safe = relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+
+        if public_id.is_empty() || !is_safe {
+            return Err(anyhow::anyhow!("Invalid public id: {}", public_id));
+        }
+
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl ImageStorage for LocalImageStorage {
+    async fn upload(
+        &self,
+        base64_image: Base64Image,
+        option: UploadImageOptions,
+    ) -> Result<UploadedImage> {
+        let bytes = base64_image.decode()?;
+        let extension = infer::get(&bytes)
+            .map(|file_type| file_type.extension())
+            .ok_or_else(|| anyhow::anyhow!("un-support file type."))?;
+
+        let name = option.public_id.unwrap_or_else(|| {
+            format!(
+                "{}_{:016x}",
+                chrono::Utc::now().timestamp(),
+                rand::random::<u64>()
+            )
+        });
+        let public_id = match option.folder {
+            Some(folder) => format!("{}/{}.{}", folder, name, extension),
+            None => format!("{}.{}", name, extension),
+        };
+
+        let path = self.resolve(&public_id)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .context(format!("Failed to create {}", parent.display()))?;
+        }
+        tokio::fs::write(&path, bytes)
+            .await
+            .context(format!("Failed to write {}", path.display()))?;
+
+        let url = format!("{}/{}", self.base_url, public_id);
+
+        Ok(UploadedImage::new(url, public_id))
+    }
+
+    async fn destroy(&self, public_id: String) -> Result<()> {
+        let path = self.resolve(&public_id)?;
+
+        match tokio::fs::remove_file(&path).await {
+            Ok(_) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(anyhow::anyhow!(
+                "Failed to remove {}: {}",
+                path.display(),
+                e
+            )),
+        }
+    }
+}
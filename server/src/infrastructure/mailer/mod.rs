@@ -0,0 +1,124 @@
+use std::{path::PathBuf, sync::Arc};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use tracing::info;
+
+use crate::{
+    config::config_model::{HttpMailerEnv, MailerBackend, MailerEnv},
+    domain::{repositories::mailer::Mailer, value_objects::mail_message::MailMessage},
+};
+
+/// Logs who outgoing mail is for, never the body: it can carry a reset link.
+/// Use `FileMailer` to read the mail itself during local development.
+pub struct LogMailer;
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, message: MailMessage) -> Result<()> {
+        info!("Mail to {} | {}", message.to, message.subject);
+        Ok(())
+    }
+}
+
+/// Drops every outgoing mail as a `.eml` file into a directory, so it can be opened locally.
+pub struct FileMailer {
+    dir: PathBuf,
+}
+
+impl FileMailer {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, message: MailMessage) -> Result<()> {
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .context(format!("Failed to create {}", self.dir.display()))?;
+
+        let now = chrono::Utc::now();
+        let path = self.dir.join(format!(
+            "{}_{:08x}.eml",
+            now.format("%Y%m%dT%H%M%S"),
+            rand::random::<u32>()
+        ));
+        let content = format!(
+            "Date: {}\r\nTo: {}\r\nSubject: {}\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n{}\r\n",
+            now.to_rfc2822(),
+            message.to,
+            message.subject,
+            message.body
+        );
+
+        tokio::fs::write(&path, content)
+            .await
+            .context(format!("Failed to write {}", path.display()))?;
+
+        info!("Mail to {} written to {}", message.to, path.display());
+        Ok(())
+    }
+}
+
+/// Sends through an email API that takes `{from, to, subject, text}` JSON with a bearer
+/// key (Resend's `/emails` shape, the default `MAILER_API_URL`).
+pub struct HttpMailer {
+    client: reqwest::Client,
+    http_env: HttpMailerEnv,
+}
+
+impl HttpMailer {
+    pub fn new(http_env: HttpMailerEnv) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            http_env,
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for HttpMailer {
+    async fn send(&self, message: MailMessage) -> Result<()> {
+        let response = self
+            .client
+            .post(&self.http_env.api_url)
+            .bearer_auth(&self.http_env.api_key)
+            .json(&serde_json::json!({
+                "from": self.http_env.from,
+                "to": [message.to],
+                "subject": message.subject,
+                "text": message.body,
+            }))
+            .send()
+            .await
+            .context(format!(
+                "Failed to send request to {}",
+                self.http_env.api_url
+            ))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            return Err(anyhow::anyhow!("Mail API failed ({}): {}", status, text));
+        }
+
+        info!("Mail to {} sent | {}", message.to, message.subject);
+        Ok(())
+    }
+}
+
+pub fn build(mailer_env: &MailerEnv) -> Result<Arc<dyn Mailer>> {
+    let mailer: Arc<dyn Mailer> = match mailer_env.backend {
+        MailerBackend::Log => Arc::new(LogMailer),
+        MailerBackend::File => Arc::new(FileMailer::new(mailer_env.dir.clone())),
+        MailerBackend::Http => {
+            Arc::new(HttpMailer::new(mailer_env.http.clone().ok_or_else(
+                || anyhow::anyhow!("Mailer API ENV is not loaded"),
+            )?))
+        }
+    };
+
+    Ok(mailer)
+}
@@ -1,5 +1,11 @@
 pub mod argon2;
 pub mod database;
 pub mod http;
+pub mod image_processing;
+pub mod image_storage;
+pub mod jobs;
 pub mod jwt;
+pub mod local_storage;
+pub mod mailer;
+pub mod oidc;
 pub mod cloudinary;
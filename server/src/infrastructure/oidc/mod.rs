@@ -0,0 +1,216 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode, decode_header, jwk::JwkSet};
+use serde::Deserialize;
+use tokio::sync::RwLock;
+
+use crate::{
+    config::config_model::OidcEnv,
+    domain::{
+        repositories::identity_provider::IdentityProvider,
+        value_objects::external_identity::{AuthorizationRequest, ExternalIdentity},
+    },
+};
+
+#[derive(Debug, Clone, Deserialize)]
+struct ProviderMetadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct IdTokenClaims {
+    sub: String,
+    nonce: Option<String>,
+    email: Option<String>,
+    email_verified: Option<bool>,
+    name: Option<String>,
+    preferred_username: Option<String>,
+}
+
+/// Authorization-code flow with PKCE against any OpenID Connect provider. Discovery and the
+/// provider's keys are fetched on first use, so the server starts even while the IdP is down.
+pub struct OidcProvider {
+    env: OidcEnv,
+    client: reqwest::Client,
+    metadata: RwLock<Option<ProviderMetadata>>,
+    jwks: RwLock<Option<JwkSet>>,
+}
+
+impl OidcProvider {
+    pub fn new(env: OidcEnv) -> Self {
+        Self {
+            env,
+            client: reqwest::Client::new(),
+            metadata: RwLock::new(None),
+            jwks: RwLock::new(None),
+        }
+    }
+
+    async fn metadata(&self) -> Result<ProviderMetadata> {
+        if let Some(metadata) = self.metadata.read().await.clone() {
+            return Ok(metadata);
+        }
+
+        let url = format!("{}/.well-known/openid-configuration", self.env.issuer);
+        let metadata = self
+            .client
+            .get(&url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<ProviderMetadata>()
+            .await?;
+
+        if metadata.issuer.trim_end_matches('/') != self.env.issuer {
+            return Err(anyhow::anyhow!(
+                "OIDC discovery issuer {} does not match OIDC_ISSUER {}",
+                metadata.issuer,
+                self.env.issuer
+            ));
+        }
+
+        *self.metadata.write().await = Some(metadata.clone());
+        Ok(metadata)
+    }
+
+    /// Refetches the provider's keys once when `kid` is unknown, so IdP rotations are picked up.
+    async fn decoding_key(&self, jwks_uri: &str, kid: Option<&str>) -> Result<DecodingKey> {
+        for refresh in [false, true] {
+            if refresh || self.jwks.read().await.is_none() {
+                let jwks = self
+                    .client
+                    .get(jwks_uri)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json::<JwkSet>()
+                    .await?;
+                *self.jwks.write().await = Some(jwks);
+            }
+
+            if let Some(jwks) = self.jwks.read().await.as_ref() {
+                let jwk = match kid {
+                    Some(kid) => jwks.find(kid),
+                    None if jwks.keys.len() == 1 => jwks.keys.first(),
+                    None => None,
+                };
+                if let Some(jwk) = jwk {
+                    return Ok(DecodingKey::from_jwk(jwk)?);
+                }
+            }
+        }
+
+        Err(anyhow::anyhow!(
+            "No key of the identity provider matches the ID token"
+        ))
+    }
+}
+
+#[async_trait]
+impl IdentityProvider for OidcProvider {
+    async fn authorization_url(&self, request: AuthorizationRequest) -> Result<String> {
+        let metadata = self.metadata().await?;
+
+        let url = reqwest::Url::parse_with_params(
+            &metadata.authorization_endpoint,
+            &[
+                ("response_type", "code"),
+                ("client_id", &self.env.client_id),
+                ("redirect_uri", &self.env.redirect_url),
+                ("scope", &self.env.scopes),
+                ("state", &request.state),
+                ("nonce", &request.nonce),
+                ("code_challenge", &request.code_challenge),
+                ("code_challenge_method", "S256"),
+            ],
+        )?;
+
+        Ok(url.to_string())
+    }
+
+    async fn exchange_code(
+        &self,
+        code: String,
+        code_verifier: String,
+        nonce: String,
+    ) -> Result<ExternalIdentity> {
+        let metadata = self.metadata().await?;
+
+        let response = self
+            .client
+            .post(&metadata.token_endpoint)
+            .basic_auth(&self.env.client_id, Some(&self.env.client_secret))
+            .form(&[
+                ("grant_type", "authorization_code"),
+                ("code", &code),
+                ("redirect_uri", &self.env.redirect_url),
+                ("client_id", &self.env.client_id),
+                ("code_verifier", &code_verifier),
+            ])
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow::anyhow!(
+                "OIDC token exchange failed ({}): {}",
+                status,
+                body
+            ));
+        }
+        let id_token = response.json::<TokenResponse>().await?.id_token;
+
+        let header = decode_header(&id_token)?;
+        // HMAC would mean trusting the client secret as a signing key; only accept provider keys.
+        if !matches!(
+            header.alg,
+            Algorithm::RS256
+                | Algorithm::RS384
+                | Algorithm::RS512
+                | Algorithm::PS256
+                | Algorithm::PS384
+                | Algorithm::PS512
+                | Algorithm::ES256
+                | Algorithm::ES384
+                | Algorithm::EdDSA
+        ) {
+            return Err(anyhow::anyhow!(
+                "Unsupported ID token algorithm {:?}",
+                header.alg
+            ));
+        }
+
+        let decoding_key = self
+            .decoding_key(&metadata.jwks_uri, header.kid.as_deref())
+            .await?;
+        let mut validation = Validation::new(header.alg);
+        validation.set_audience(&[&self.env.client_id]);
+        validation.set_issuer(&[&metadata.issuer]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+        let claims = decode::<IdTokenClaims>(&id_token, &decoding_key, &validation)?.claims;
+        if claims.nonce.as_deref() != Some(nonce.as_str()) {
+            return Err(anyhow::anyhow!("ID token nonce does not match"));
+        }
+
+        Ok(ExternalIdentity {
+            issuer: self.env.issuer.clone(),
+            subject: claims.sub,
+            // An address the provider hasn't verified says nothing about who signed in.
+            email: claims
+                .email
+                .filter(|_| claims.email_verified != Some(false)),
+            name: claims.name,
+            preferred_username: claims.preferred_username,
+        })
+    }
+}
@@ -1,31 +1,53 @@
 use std::sync::Arc;
 
 use league_of_legends::{
-    config::config_loader,
-    infrastructure::{database::postgresql_connection, http::http_serv::start},
+    config::{config_loader, stage::Stage},
+    infrastructure::{
+        database::postgresql_connection,
+        http::{app_state::AppState, http_serv::start},
+        image_storage,
+        jobs::{leaderboard_refresh, mission_purge},
+        jwt::keys::JwtKeys,
+        mailer,
+    },
 };
 use tracing::{error, info};
 
 #[tokio::main]
 async fn main() {
-    tracing_subscriber::fmt()
-        .with_max_level(tracing::Level::DEBUG)
-        .init();
+    let config = config_loader::load();
+    // A config that fails to load still gets logged, with the non-Prod format.
+    init_tracing(
+        config
+            .as_ref()
+            .map(|config| config.stage.clone())
+            .unwrap_or_default(),
+    );
 
-    let dotenvy_env = match config_loader::load() {
-        Ok(env) => env,
+    let config = match config {
+        Ok(config) => config,
         Err(e) => {
-            error!("Failed to load ENV: {}", e);
+            error!("Failed to load config: {}", e);
             std::process::exit(1);
         }
     };
 
-    info!(".ENV LOADED");
+    match &config.report.file {
+        Some(file) => info!(
+            "CONFIG LOADED (stage: {}, file: {})",
+            config.stage,
+            file.display()
+        ),
+        None => info!("CONFIG LOADED (stage: {}, no config file)", config.stage),
+    }
+    for setting in &config.report.settings {
+        info!("  {} = {} ({})", setting.key, setting.value, setting.source);
+    }
 
     let mut retry_count = 0;
     let max_retries = 100;
     let postgres_pool = loop {
-        match postgresql_connection::establish_connection(&dotenvy_env.database.url) {
+        match postgresql_connection::establish_connection(&config.database.url) {
             Ok(pool) => break pool,
             Err(err) => {
                 retry_count += 1;
@@ -43,7 +65,64 @@ async fn main() {
     };
     info!("Connected DB");
 
-    start(Arc::new(dotenvy_env), Arc::new(postgres_pool))
-        .await
-        .expect("Failed to start server");
+    let postgres_pool = Arc::new(postgres_pool);
+
+    let image_storage = match image_storage::build(&config.image_storage) {
+        Ok(storage) => storage,
+        Err(e) => {
+            error!("Failed to set up image storage: {}", e);
+            std::process::exit(1);
+        }
+    };
+    info!("Image storage: {:?}", config.image_storage.backend);
+
+    let mailer = match mailer::build(&config.mailer) {
+        Ok(mailer) => mailer,
+        Err(e) => {
+            error!("Failed to set up mailer: {}", e);
+            std::process::exit(1);
+        }
+    };
+    info!("Mailer: {:?}", config.mailer.backend);
+
+    let jwt_keys = match JwtKeys::load(&config.jwt) {
+        Ok(keys) => Arc::new(keys),
+        Err(e) => {
+            error!("Failed to load JWT keys: {}", e);
+            std::process::exit(1);
+        }
+    };
+    info!(
+        "JWT: {:?} (kid: {})",
+        jwt_keys.algorithm(),
+        jwt_keys.signing_key_id().unwrap_or("-")
+    );
+
+    if config.features.leaderboard_refresh {
+        leaderboard_refresh::spawn(Arc::clone(&postgres_pool), config.leaderboard.clone());
+    }
+    if config.features.mission_purge {
+        mission_purge::spawn(
+            Arc::clone(&postgres_pool),
+            Arc::clone(&image_storage),
+            config.mission_trash.clone(),
+        );
+    }
+
+    let state = AppState::new(config, postgres_pool, image_storage, mailer, jwt_keys);
+
+    start(state).await.expect("Failed to start server");
+}
+
+/// Prod logs JSON lines at INFO for the log collector; Local and Dev keep readable DEBUG output.
+fn init_tracing(stage: Stage) {
+    match stage {
+        Stage::Production => tracing_subscriber::fmt()
+            .json()
+            .with_max_level(tracing::Level::INFO)
+            .init(),
+        Stage::Local | Stage::Development => tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .init(),
+    }
 }